@@ -4,6 +4,7 @@ use crate::text::version::semantic::Version;
 pub mod fs;
 pub mod text;
 pub mod number;
+pub mod time;
 
 /// Returns version of `tbx_essential` module.
 pub fn version<'a>() -> Version<'a> {
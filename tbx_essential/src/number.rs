@@ -1,3 +1,8 @@
 pub mod byte;
+pub mod crc32;
+pub mod ct;
+pub mod fnv;
+pub mod integer;
 pub mod primitive;
 pub mod random;
+pub mod sha256;
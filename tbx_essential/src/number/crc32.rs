@@ -0,0 +1,43 @@
+use std::sync::OnceLock;
+
+/// Reversed representation of the IEEE 802.3 polynomial `0x04C11DB7`.
+const POLYNOMIAL: u32 = 0xEDB8_8320;
+
+static TABLE: OnceLock<[u32; 256]> = OnceLock::new();
+
+/// Lookup table of the CRC for each byte value. Computed on the first use.
+fn table() -> &'static [u32; 256] {
+    TABLE.get_or_init(|| {
+        let mut table = [0u32; 256];
+        for (i, entry) in table.iter_mut().enumerate() {
+            let mut c = i as u32;
+            for _ in 0..8 {
+                c = if c & 1 == 1 { POLYNOMIAL ^ (c >> 1) } else { c >> 1 };
+            }
+            *entry = c;
+        }
+        table
+    })
+}
+
+/// CRC-32 checksum (IEEE 802.3, same as zlib, gzip and PNG) of the bytes.
+/// Use [`crate::text::hex::Hex`] for the hex string like `crc32(b"123456789").to_hex_lower()`.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    let table = table();
+    !bytes.iter().fold(!0u32, |crc, b| table[((crc ^ *b as u32) & 0xff) as usize] ^ (crc >> 8))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::number::crc32::crc32;
+    use crate::text::hex::Hex;
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(0xCBF4_3926, crc32(b"123456789"));
+        assert_eq!(0, crc32(b""));
+        assert_eq!(0xE8B7_BE43, crc32(b"a"));
+        assert_eq!(0x414F_A339, crc32(b"The quick brown fox jumps over the lazy dog"));
+        assert_eq!("cbf43926", crc32(b"123456789").to_hex_lower());
+    }
+}
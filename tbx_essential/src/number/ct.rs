@@ -0,0 +1,36 @@
+use std::hint::black_box;
+
+/// Compare the byte slices in constant time, for secrets like MACs or tokens.
+/// The time depends only on the length, not on the contents or the position of the first difference.
+///
+/// Caveat: returns false immediately if the lengths differ, then the length is not a secret.
+/// Compare fixed-length values (e.g. digests, or decoded bytes of the hex string) to avoid the length leak.
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| black_box(acc | (x ^ y)));
+    diff == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::number::ct::ct_eq;
+    use crate::text::hex;
+
+    #[test]
+    fn test_ct_eq() {
+        assert!(ct_eq(b"", b""));
+        assert!(ct_eq(b"secret", b"secret"));
+        assert!(!ct_eq(b"secret", b"secreT"));
+        assert!(!ct_eq(b"secret", b"Secret"));
+
+        // length mismatch
+        assert!(!ct_eq(b"secret", b"secret!"));
+        assert!(!ct_eq(b"", b"a"));
+
+        let mac = hex::parse("cbf43926").unwrap();
+        assert!(ct_eq(&mac, &hex::parse("CBF43926").unwrap()));
+        assert!(!ct_eq(&mac, &hex::parse("cbf43927").unwrap()));
+    }
+}
@@ -0,0 +1,35 @@
+const FNV_OFFSET_BASIS_32: u32 = 0x811C_9DC5;
+const FNV_PRIME_32: u32 = 0x0100_0193;
+const FNV_OFFSET_BASIS_64: u64 = 0xCBF2_9CE4_8422_2325;
+const FNV_PRIME_64: u64 = 0x0000_0100_0000_01B3;
+
+/// 32-bit FNV-1a hash of the bytes. This is not a cryptographic hash.
+/// see: <http://www.isthe.com/chongo/tech/comp/fnv/>
+pub fn fnv1a_32(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(FNV_OFFSET_BASIS_32, |h, b| (h ^ *b as u32).wrapping_mul(FNV_PRIME_32))
+}
+
+/// 64-bit FNV-1a hash of the bytes. This is not a cryptographic hash.
+/// see: <http://www.isthe.com/chongo/tech/comp/fnv/>
+pub fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(FNV_OFFSET_BASIS_64, |h, b| (h ^ *b as u64).wrapping_mul(FNV_PRIME_64))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::number::fnv::{fnv1a_32, fnv1a_64};
+
+    #[test]
+    fn test_fnv1a_32() {
+        assert_eq!(0x811C_9DC5, fnv1a_32(b""));
+        assert_eq!(0xE40C_292C, fnv1a_32(b"a"));
+        assert_eq!(0xBF9C_F968, fnv1a_32(b"foobar"));
+    }
+
+    #[test]
+    fn test_fnv1a_64() {
+        assert_eq!(0xCBF2_9CE4_8422_2325, fnv1a_64(b""));
+        assert_eq!(0xAF63_DC4C_8601_EC8C, fnv1a_64(b"a"));
+        assert_eq!(0x8594_4171_F739_67E8, fnv1a_64(b"foobar"));
+    }
+}
@@ -0,0 +1,62 @@
+/// Greatest common divisor and least common multiple of unsigned integers.
+pub trait Gcd: Sized {
+    /// Greatest common divisor by Euclid's algorithm.
+    /// Returns `other` if `self` is zero (`gcd(0, n) == n`), and zero for `gcd(0, 0)`.
+    fn gcd(&self, other: &Self) -> Self;
+
+    /// Least common multiple.
+    /// Returns `Some(0)` if either is zero (`lcm(0, n) == 0`), and `None` on overflow.
+    fn lcm(&self, other: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_gcd {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Gcd for $t {
+                fn gcd(&self, other: &Self) -> Self {
+                    let (mut a, mut b) = (*self, *other);
+                    while b != 0 {
+                        (a, b) = (b, a % b);
+                    }
+                    a
+                }
+
+                fn lcm(&self, other: &Self) -> Option<Self> {
+                    if *self == 0 || *other == 0 {
+                        Some(0)
+                    } else {
+                        // divide first to avoid overflow of the intermediate product
+                        (*self / self.gcd(other)).checked_mul(*other)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_gcd!(u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use crate::number::integer::Gcd;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(6, 54u32.gcd(&24));
+        assert_eq!(6, 24u64.gcd(&54));
+        assert_eq!(1, 17u128.gcd(&5));
+        assert_eq!(7, 0u32.gcd(&7));
+        assert_eq!(7, 7u64.gcd(&0));
+        assert_eq!(0, 0u128.gcd(&0));
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(Some(12), 4u32.lcm(&6));
+        assert_eq!(Some(12), 6u64.lcm(&4));
+        assert_eq!(Some(0), 0u128.lcm(&5));
+        assert_eq!(Some(0), 5u32.lcm(&0));
+        assert_eq!(Some(u64::MAX), u64::MAX.lcm(&u64::MAX));
+        assert_eq!(None, u32::MAX.lcm(&(u32::MAX - 1)));
+    }
+}
@@ -0,0 +1,367 @@
+//! Traits of the primitive numbers.
+//!
+//! Some traits mirror inherent methods of the primitives, like `checked_add`, `abs` or `pow`.
+//! Inherent methods take precedence on method call syntax, then `x.abs()` calls the inherent method.
+//! Use these traits in generic code, or call like `Signed::abs(x)`.
+
+use std::ops::{Add, Div, Mul, Rem, Sub};
+use crate::number::integer::Gcd;
+
+/// Arithmetic operations of the primitive numbers.
+/// This trait is implemented for all types that implement the operators.
+pub trait NumberOps<Rhs = Self, Output = Self>:
+Add<Rhs, Output=Output> +
+Sub<Rhs, Output=Output> +
+Mul<Rhs, Output=Output> +
+Div<Rhs, Output=Output> +
+Rem<Rhs, Output=Output> {}
+
+impl<T, Rhs, Output> NumberOps<Rhs, Output> for T where T:
+Add<Rhs, Output=Output> +
+Sub<Rhs, Output=Output> +
+Mul<Rhs, Output=Output> +
+Div<Rhs, Output=Output> +
+Rem<Rhs, Output=Output> {}
+
+/// Additive identity.
+pub trait Zero: Sized {
+    /// Returns the additive identity, `0`.
+    fn zero() -> Self;
+
+    /// Returns true if the value is the additive identity.
+    fn is_zero(&self) -> bool;
+}
+
+/// Multiplicative identity.
+pub trait One: Sized {
+    /// Returns the multiplicative identity, `1`.
+    fn one() -> Self;
+}
+
+/// Primitive numbers, the integer and floating point types.
+/// This trait is implemented for all types that have arithmetic operations and identities.
+pub trait Number: PartialEq + PartialOrd + NumberOps + Zero + One {}
+
+impl<T> Number for T where T: PartialEq + PartialOrd + NumberOps + Zero + One {}
+
+/// Overflow-safe arithmetic operations.
+/// Each operation returns `None` on overflow (or division by zero) instead of panicking or wrapping.
+pub trait CheckedOps: Sized {
+    /// Checked addition.
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+
+    /// Checked subtraction.
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+
+    /// Checked multiplication.
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+
+    /// Checked division. Returns `None` if `rhs` is zero.
+    fn checked_div(&self, rhs: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_ops {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl CheckedOps for $t {
+                fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *rhs)
+                }
+
+                fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_sub(*self, *rhs)
+                }
+
+                fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_mul(*self, *rhs)
+                }
+
+                fn checked_div(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_div(*self, *rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_ops!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_identity {
+    ($($t:ty, $zero:expr, $one:expr);* $(;)?) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+
+                fn is_zero(&self) -> bool {
+                    *self == $zero
+                }
+            }
+
+            impl One for $t {
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
+}
+
+impl_identity! {
+    u8, 0, 1;
+    u16, 0, 1;
+    u32, 0, 1;
+    u64, 0, 1;
+    u128, 0, 1;
+    usize, 0, 1;
+    i8, 0, 1;
+    i16, 0, 1;
+    i32, 0, 1;
+    i64, 0, 1;
+    i128, 0, 1;
+    isize, 0, 1;
+    f32, 0.0, 1.0;
+    f64, 0.0, 1.0;
+}
+
+/// Sign operations of the signed integer and floating point types.
+pub trait Signed: Sized {
+    /// Absolute value.
+    /// For integers, this follows the overflow behavior of std: `i32::MIN.abs()` panics in debug builds
+    /// and returns `i32::MIN` in release builds.
+    fn abs(self) -> Self;
+
+    /// Sign of the value.
+    /// For integers, returns `-1`, `0` or `1`.
+    /// For floats, returns `-1.0` or `1.0` (including `-0.0` and `0.0`), or `NaN` if the value is `NaN`.
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Signed for $t {
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// Power of the integer primitives.
+pub trait IntegerPow: Sized {
+    /// Raises self to the power of `exp`. Overflow follows the behavior of std.
+    fn pow(self, exp: u32) -> Self;
+}
+
+macro_rules! impl_integer_pow {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl IntegerPow for $t {
+                fn pow(self, exp: u32) -> Self {
+                    <$t>::pow(self, exp)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_pow!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Power of the floating point primitives.
+pub trait FloatPow: Sized {
+    /// Raises self to the integer power `n`.
+    fn powi(self, n: i32) -> Self;
+
+    /// Raises self to the floating point power `n`.
+    fn powf(self, n: Self) -> Self;
+}
+
+macro_rules! impl_float_pow {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl FloatPow for $t {
+                fn powi(self, n: i32) -> Self {
+                    <$t>::powi(self, n)
+                }
+
+                fn powf(self, n: Self) -> Self {
+                    <$t>::powf(self, n)
+                }
+            }
+        )*
+    };
+}
+
+impl_float_pow!(f32, f64);
+
+/// Greatest common divisor of `a` and `b`. Returns `b` if `a` is zero.
+/// See [`Gcd::gcd`].
+pub fn gcd(a: u64, b: u64) -> u64 {
+    a.gcd(&b)
+}
+
+/// Least common multiple of `a` and `b`. Returns `None` on overflow.
+/// See [`Gcd::lcm`].
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    a.lcm(&b)
+}
+
+/// Returns the smaller of `a` and `b`. Returns `a` if they are equal or not comparable (e.g. `NaN`).
+pub fn min<T: PartialOrd + Copy>(a: T, b: T) -> T {
+    if b < a { b } else { a }
+}
+
+/// Returns the larger of `a` and `b`. Returns `a` if they are equal or not comparable (e.g. `NaN`).
+pub fn max<T: PartialOrd + Copy>(a: T, b: T) -> T {
+    if a < b { b } else { a }
+}
+
+/// Restrict `value` to the range `lo..=hi`.
+/// Panics if `lo` is greater than `hi` (or they are not comparable).
+pub fn clamp<T: PartialOrd + Copy>(value: T, lo: T, hi: T) -> T {
+    assert!(lo <= hi, "lo must be less than or equal to hi");
+    if value < lo {
+        lo
+    } else if hi < value {
+        hi
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::number::primitive::{clamp, gcd, lcm, max, min, CheckedOps, FloatPow, IntegerPow, Number, NumberOps, One, Signed, Zero};
+
+    fn sum<T: Number + Copy>(xs: &[T]) -> T {
+        xs.iter().fold(T::zero(), |acc, x| acc + *x)
+    }
+
+    fn product<T: Number + Copy>(xs: &[T]) -> T {
+        xs.iter().fold(T::one(), |acc, x| acc * *x)
+    }
+
+    #[test]
+    fn test_identity() {
+        assert_eq!(0, u8::zero());
+        assert_eq!(1, i128::one());
+        assert_eq!(0.0, f32::zero());
+        assert_eq!(1.0, f64::one());
+
+        assert!(0usize.is_zero());
+        assert!(!(-1i64).is_zero());
+        assert!((-0.0f64).is_zero());
+        assert!(!f64::NAN.is_zero());
+    }
+
+    #[test]
+    fn test_sum() {
+        assert_eq!(6, sum(&[1i32, 2, 3]));
+        assert_eq!(0, sum::<i32>(&[]));
+        assert_eq!(4.0, sum(&[1.5f64, 2.5]));
+        assert_eq!(0.0, sum::<f64>(&[]));
+
+        assert_eq!(24, product(&[2u64, 3, 4]));
+        assert_eq!(1.0, product::<f32>(&[]));
+    }
+
+    fn checked_sum<T: CheckedOps + Zero>(xs: &[T]) -> Option<T> {
+        xs.iter().try_fold(T::zero(), |acc, x| acc.checked_add(x))
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        assert_eq!(None, CheckedOps::checked_add(&u8::MAX, &1));
+        assert_eq!(Some(255), CheckedOps::checked_add(&254u8, &1));
+        assert_eq!(None, CheckedOps::checked_sub(&0u32, &1));
+        assert_eq!(Some(-1), CheckedOps::checked_sub(&0i32, &1));
+        assert_eq!(None, CheckedOps::checked_mul(&i64::MAX, &2));
+        assert_eq!(Some(12), CheckedOps::checked_mul(&3usize, &4));
+        assert_eq!(None, CheckedOps::checked_div(&1u16, &0));
+        assert_eq!(None, CheckedOps::checked_div(&i8::MIN, &-1));
+        assert_eq!(Some(3), CheckedOps::checked_div(&7i128, &2));
+
+        assert_eq!(Some(255), checked_sum(&[200u8, 50, 5]));
+        assert_eq!(None, checked_sum(&[200u8, 50, 6]));
+    }
+
+    fn distance<T: Signed + NumberOps>(a: T, b: T) -> T {
+        (a - b).abs()
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(5, Signed::abs(-5i32));
+        assert_eq!(5, Signed::abs(5i8));
+        assert_eq!(-1.0, Signed::signum(-3.0f64));
+        assert_eq!(1.0, Signed::signum(0.0f32));
+        assert!(Signed::signum(f64::NAN).is_nan());
+        assert_eq!(-1, Signed::signum(-7i128));
+        assert_eq!(0, Signed::signum(0isize));
+
+        assert_eq!(3, distance(2i64, 5));
+        assert_eq!(1.5, distance(-1.0f64, 0.5));
+    }
+
+    fn square<T: IntegerPow>(x: T) -> T {
+        x.pow(2)
+    }
+
+    #[test]
+    fn test_pow() {
+        assert_eq!(1024, IntegerPow::pow(2u32, 10));
+        assert_eq!(-8, IntegerPow::pow(-2i64, 3));
+        assert_eq!(1, IntegerPow::pow(7u8, 0));
+        assert_eq!(49, square(7usize));
+
+        assert_eq!(8.0, FloatPow::powi(2.0f64, 3));
+        assert_eq!(0.25, FloatPow::powi(2.0f32, -2));
+        assert_eq!(3.0, FloatPow::powf(9.0f64, 0.5));
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(6, gcd(12, 18));
+        assert_eq!(5, gcd(0, 5));
+        assert_eq!(5, gcd(5, 0));
+        assert_eq!(Some(12), lcm(4, 6));
+        assert_eq!(Some(0), lcm(0, 6));
+        assert_eq!(None, lcm(u64::MAX, u64::MAX - 1));
+    }
+
+    #[test]
+    fn test_min_max() {
+        assert_eq!(1, min(1i32, 2));
+        assert_eq!(2, max(1i32, 2));
+        assert_eq!(-2, min(3i32, -2));
+        assert_eq!(-1.5, min(-1.5f64, 0.5));
+        assert_eq!(0.5, max(-1.5f64, 0.5));
+    }
+
+    #[test]
+    fn test_clamp() {
+        assert_eq!(0, clamp(-5i32, 0, 10));
+        assert_eq!(5, clamp(5i32, 0, 10));
+        assert_eq!(10, clamp(15i32, 0, 10));
+        assert_eq!(3, clamp(7i32, 3, 3));
+
+        assert_eq!(-1.0, clamp(-1.5f64, -1.0, 1.0));
+        assert_eq!(0.25, clamp(0.25f64, -1.0, 1.0));
+        assert_eq!(1.0, clamp(2.0f64, -1.0, 1.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_invalid_range() {
+        clamp(1i32, 10, 0);
+    }
+}
@@ -1,6 +1,7 @@
 use std::ops::Range;
-use rand::{Rng};
+use rand::{Error, Rng, RngCore, SeedableRng};
 use rand::prelude::ThreadRng;
+use rand::rngs::StdRng;
 
 /// Pseudo-random number generator.
 ///
@@ -103,17 +104,128 @@ pub trait Generator {
 
     /// Generate pseudo-random numbers within the specified scope.
     fn next_range_isize(&mut self, range: Range<isize>) -> isize;
+
+    /// Generate pseudo-random numbers within the specified scope `[start, end)`.
+    /// Panics if the range is empty (`start >= end`), as same as integer variants.
+    fn next_range_f32(&mut self, range: Range<f32>) -> f32;
+
+    /// Generate pseudo-random numbers within the specified scope `[start, end)`.
+    /// Panics if the range is empty (`start >= end`), as same as integer variants.
+    fn next_range_f64(&mut self, range: Range<f64>) -> f64;
+
+    /// Generate next random number from the normal distribution with `mean` and `std_dev`.
+    /// `std_dev` must be positive.
+    /// This function uses the Box–Muller transform with two draws of [`Generator::next_f64`].
+    fn next_gaussian(&mut self, mean: f64, std_dev: f64) -> f64 {
+        // `1 - [0, 1)` = `(0, 1]`, to avoid `ln(0)`
+        let u1 = 1.0 - self.next_f64();
+        let u2 = self.next_f64();
+        let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+        mean + std_dev * z
+    }
+
+    /// Choose an item randomly, with probability proportional to the corresponding weight.
+    /// Weights must be non-negative, and an item with zero weight is never chosen.
+    /// Returns `None` if slices are empty, lengths mismatch, or the sum of weights is not positive.
+    fn choose_weighted<'a, T>(&mut self, items: &'a [T], weights: &[f64]) -> Option<&'a T> {
+        if items.is_empty() || items.len() != weights.len() || weights.iter().any(|w| w.is_nan() || *w < 0.0) {
+            return None;
+        }
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 || total.is_infinite() {
+            return None;
+        }
+
+        let r = self.next_f64() * total;
+        let mut cumulative = 0.0;
+        for (item, weight) in items.iter().zip(weights) {
+            cumulative += weight;
+            if r < cumulative {
+                return Some(item);
+            }
+        }
+        // fallback for the rounding error of the cumulative sum
+        items.iter().zip(weights).rev().find(|(_, w)| 0.0 < **w).map(|(item, _)| item)
+    }
+
+    /// Returns uniformly random permutation of `0..n` by Fisher-Yates shuffle.
+    fn permutation(&mut self, n: usize) -> Vec<usize> {
+        let mut p: Vec<usize> = (0..n).collect();
+        for i in (1..n).rev() {
+            let j = self.next_range_usize(0..i + 1);
+            p.swap(i, j);
+        }
+        p
+    }
+
+    /// Returns `min(k, n)` distinct indices chosen uniformly from `0..n`, in random order.
+    /// This function uses the partial Fisher-Yates shuffle, then `k >= n` yields a permutation of `0..n`.
+    fn sample_indices(&mut self, n: usize, k: usize) -> Vec<usize> {
+        let k = k.min(n);
+        let mut p: Vec<usize> = (0..n).collect();
+        for i in 0..k {
+            let j = self.next_range_usize(i..n);
+            p.swap(i, j);
+        }
+        p.truncate(k);
+        p
+    }
+}
+
+/// Underlying random number generators of [`Random`].
+enum Source {
+    ThreadLocal(ThreadRng),
+    Std(Box<StdRng>),
+}
+
+impl RngCore for Source {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            Source::ThreadLocal(r) => r.next_u32(),
+            Source::Std(r) => r.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            Source::ThreadLocal(r) => r.next_u64(),
+            Source::Std(r) => r.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            Source::ThreadLocal(r) => r.fill_bytes(dest),
+            Source::Std(r) => r.fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        match self {
+            Source::ThreadLocal(r) => r.try_fill_bytes(dest),
+            Source::Std(r) => r.try_fill_bytes(dest),
+        }
+    }
 }
 
 pub struct Random {
-    rng: ThreadRng,
+    rng: Source,
 }
 
 impl Random {
     /// Generate new thread local pseudo-Random generator.
     pub fn new_thread_local() -> Self {
         Self {
-            rng: rand::thread_rng()
+            rng: Source::ThreadLocal(rand::thread_rng())
+        }
+    }
+
+    /// Generate new cryptographically secure pseudo-random generator ([`rand::rngs::StdRng`]),
+    /// seeded from the entropy source of the operating system.
+    /// Intended for one-off secure needs like tokens or keys, rather than a large number of values.
+    pub fn from_entropy() -> Self {
+        Self {
+            rng: Source::Std(Box::new(StdRng::from_entropy()))
         }
     }
 }
@@ -230,6 +342,14 @@ impl Generator for Random {
     fn next_range_isize(&mut self, range: Range<isize>) -> isize {
         self.rng.gen_range(range)
     }
+
+    fn next_range_f32(&mut self, range: Range<f32>) -> f32 {
+        self.rng.gen_range(range)
+    }
+
+    fn next_range_f64(&mut self, range: Range<f64>) -> f64 {
+        self.rng.gen_range(range)
+    }
 }
 
 
@@ -320,5 +440,134 @@ mod random {
         let _r: i64 = r.next_range_i64(-10..10);
         let _r: i128 = r.next_range_i128(-10..10);
         let _r: isize = r.next_range_isize(-10..10);
+
+        // float range
+        let _r: f32 = r.next_range_f32(-1.0..1.0);
+        let _r: f64 = r.next_range_f64(-1.0..1.0);
+    }
+
+    #[test]
+    fn test_next_range_float() {
+        let mut r = Random::new_thread_local();
+
+        verify_next(&mut r, |r| { r.next_range_f32(-10.0..10.0) });
+        verify_next(&mut r, |r| { r.next_range_f64(-10.0..10.0) });
+
+        for _ in 0..10_000 {
+            let x = r.next_range_f32(1.5..2.5);
+            assert!((1.5..2.5).contains(&x), "{}", x);
+            let y = r.next_range_f64(-0.25..0.0);
+            assert!((-0.25..0.0).contains(&y), "{}", y);
+        }
+    }
+
+    #[test]
+    fn test_next_gaussian() {
+        let mut r = Random::new_thread_local();
+        let n = 100_000;
+        let samples: Vec<f64> = (0..n).map(|_| r.next_gaussian(10.0, 2.0)).collect();
+
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+        assert!((mean - 10.0).abs() < 0.05, "mean: {}", mean);
+        assert!((variance.sqrt() - 2.0).abs() < 0.05, "std_dev: {}", variance.sqrt());
+        assert!(samples.iter().all(|x| x.is_finite()));
+    }
+
+    #[test]
+    fn test_choose_weighted() {
+        let mut r = Random::new_thread_local();
+        let items = ["zero", "one"];
+
+        for _ in 0..10_000 {
+            assert_eq!(Some(&"one"), r.choose_weighted(&items, &[0.0, 1.0]));
+        }
+
+        let items = [1, 2, 3];
+        let mut counts = [0; 3];
+        for _ in 0..10_000 {
+            let c = r.choose_weighted(&items, &[1.0, 0.0, 3.0]).unwrap();
+            counts[c - 1] += 1;
+        }
+        assert_eq!(0, counts[1]);
+        assert!(counts[0] > 0 && counts[0] < counts[2], "{:?}", counts);
+
+        let empty: [i32; 0] = [];
+        assert_eq!(None, r.choose_weighted(&empty, &[]));
+        assert_eq!(None, r.choose_weighted(&items, &[1.0, 2.0]));
+        assert_eq!(None, r.choose_weighted(&items, &[0.0, 0.0, 0.0]));
+        assert_eq!(None, r.choose_weighted(&items, &[1.0, -1.0, 1.0]));
+        assert_eq!(None, r.choose_weighted(&items, &[1.0, f64::NAN, 1.0]));
+    }
+
+    #[test]
+    fn test_permutation() {
+        let mut r = Random::new_thread_local();
+
+        assert!(r.permutation(0).is_empty());
+        assert_eq!(vec![0], r.permutation(1));
+
+        let n = 10;
+        let mut seen = vec![vec![false; n]; n];
+        for _ in 0..1_000 {
+            let p = r.permutation(n);
+            let mut sorted = p.clone();
+            sorted.sort();
+            assert_eq!((0..n).collect::<Vec<usize>>(), sorted);
+            for (position, value) in p.iter().enumerate() {
+                seen[position][*value] = true;
+            }
+        }
+        // every value appears at every position
+        assert!(seen.iter().all(|s| s.iter().all(|x| *x)));
+    }
+
+    #[test]
+    fn test_sample_indices() {
+        let mut r = Random::new_thread_local();
+
+        assert!(r.sample_indices(0, 3).is_empty());
+        assert!(r.sample_indices(10, 0).is_empty());
+
+        let mut seen = [false; 10];
+        for _ in 0..1_000 {
+            let s = r.sample_indices(10, 3);
+            assert_eq!(3, s.len());
+            let mut dedup = s.clone();
+            dedup.sort();
+            dedup.dedup();
+            assert_eq!(3, dedup.len());
+            for i in s {
+                assert!(i < 10);
+                seen[i] = true;
+            }
+        }
+        assert!(seen.iter().all(|x| *x));
+
+        let mut all = r.sample_indices(5, 8);
+        all.sort();
+        assert_eq!(vec![0, 1, 2, 3, 4], all);
+    }
+
+    fn next_pair<G: Generator>(g: &mut G) -> (u64, f64) {
+        (g.next_u64(), g.next_range_f64(0.0..1.0))
+    }
+
+    #[test]
+    fn test_from_entropy() {
+        let mut r1 = Random::from_entropy();
+        let mut r2 = Random::from_entropy();
+        assert_ne!(r1.next_u64(), r2.next_u64());
+
+        let (_, f) = next_pair(&mut r1);
+        assert!((0.0..1.0).contains(&f));
+        assert_eq!(5, r1.permutation(5).len());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_next_range_float_empty() {
+        let mut r = Random::new_thread_local();
+        r.next_range_f64(1.0..1.0);
     }
 }
\ No newline at end of file
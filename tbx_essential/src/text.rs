@@ -1,7 +1,13 @@
+pub mod base32;
+pub mod base64;
+pub mod codec;
 pub mod essential;
 pub mod hex;
 pub mod parser;
 pub mod pattern;
+pub mod percent;
+pub mod progress;
+pub mod query;
 pub mod random;
 pub mod regex;
 pub mod token;
@@ -0,0 +1,98 @@
+pub mod error;
+
+use crate::text::base32::error::ParseError;
+
+const ALPHABET_CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Encode to Crockford's Base32 string (upper case, without padding).
+/// The last symbol is filled with zero bits if the bit length is not a multiple of 5.
+pub fn encode_crockford(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity((bytes.len() * 8).div_ceil(5));
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+    for b in bytes {
+        buffer = buffer << 8 | *b as u16;
+        bits += 8;
+        while 5 <= bits {
+            bits -= 5;
+            out.push(ALPHABET_CROCKFORD[(buffer >> bits & 0x1f) as usize] as char);
+        }
+    }
+    if 0 < bits {
+        out.push(ALPHABET_CROCKFORD[(buffer << (5 - bits) & 0x1f) as usize] as char);
+    }
+    out
+}
+
+fn decode_char(c: char) -> Result<u16, ParseError> {
+    match c.to_ascii_uppercase() {
+        'I' | 'L' | 'O' | 'U' => Err(ParseError::InvalidChar),
+        u @ ('0'..='9' | 'A'..='Z') => {
+            Ok(ALPHABET_CROCKFORD.iter().position(|a| *a as char == u).unwrap() as u16)
+        }
+        _ => Err(ParseError::InvalidChar),
+    }
+}
+
+/// Decode Crockford's Base32 string. This function is case-insensitive.
+/// Letters `I`, `L`, `O` and `U` are not part of the alphabet, and rejected as invalid.
+pub fn decode_crockford(s: &str) -> Result<Vec<u8>, ParseError> {
+    if matches!(s.len() % 8, 1 | 3 | 6) {
+        return Err(ParseError::InvalidLength);
+    }
+
+    let mut out: Vec<u8> = Vec::with_capacity(s.len() * 5 / 8);
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+    for c in s.chars() {
+        buffer = (buffer << 5 | decode_char(c)?) & 0x0fff;
+        bits += 5;
+        if 8 <= bits {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::text::base32::{decode_crockford, encode_crockford};
+    use crate::text::base32::error::ParseError::{InvalidChar, InvalidLength};
+
+    #[test]
+    fn test_encode_crockford() {
+        assert_eq!("", encode_crockford(b""));
+        assert_eq!("CR", encode_crockford(b"f"));
+        assert_eq!("CSQG", encode_crockford(b"fo"));
+        assert_eq!("CSQPY", encode_crockford(b"foo"));
+        assert_eq!("CSQPYRG", encode_crockford(b"foob"));
+        assert_eq!("CSQPYRK1", encode_crockford(b"fooba"));
+        assert_eq!("CSQPYRK1E8", encode_crockford(b"foobar"));
+    }
+
+    #[test]
+    fn test_decode_crockford() {
+        assert_eq!(Ok(b"".to_vec()), decode_crockford(""));
+        assert_eq!(Ok(b"f".to_vec()), decode_crockford("CR"));
+        assert_eq!(Ok(b"foobar".to_vec()), decode_crockford("CSQPYRK1E8"));
+        assert_eq!(Ok(b"foobar".to_vec()), decode_crockford("csqpyrk1e8"));
+
+        assert_eq!(Err(InvalidChar), decode_crockford("CSQPYRK1EL"));
+        assert_eq!(Err(InvalidChar), decode_crockford("0O"));
+        assert_eq!(Err(InvalidChar), decode_crockford("CU"));
+        assert_eq!(Err(InvalidChar), decode_crockford("C-"));
+        assert_eq!(Err(InvalidLength), decode_crockford("C"));
+        assert_eq!(Err(InvalidLength), decode_crockford("CSQ"));
+        assert_eq!("invalid character", decode_crockford("CU").unwrap_err().to_string());
+        assert_eq!("invalid length", decode_crockford("C").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            assert_eq!(Ok(data[..len].to_vec()), decode_crockford(&encode_crockford(&data[..len])));
+        }
+    }
+}
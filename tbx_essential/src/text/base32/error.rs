@@ -0,0 +1,2 @@
+/// Base32 shares the error type with other codecs in [`crate::text::codec`].
+pub use crate::text::codec::error::ParseError;
@@ -0,0 +1,144 @@
+pub mod error;
+
+use crate::text::base64::error::ParseError;
+
+const ALPHABET_STANDARD: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+pub(crate) const ALPHABET_URL_SAFE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const PADDING: char = '=';
+
+fn encode_with(bytes: &[u8], alphabet: &[u8; 64], padding: bool) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as usize;
+        let b1 = chunk.get(1).map_or(0, |b| *b as usize);
+        let b2 = chunk.get(2).map_or(0, |b| *b as usize);
+
+        out.push(alphabet[b0 >> 2] as char);
+        out.push(alphabet[(b0 & 0x03) << 4 | b1 >> 4] as char);
+        if 1 < chunk.len() {
+            out.push(alphabet[(b1 & 0x0f) << 2 | b2 >> 6] as char);
+        } else if padding {
+            out.push(PADDING);
+        }
+        if 2 < chunk.len() {
+            out.push(alphabet[b2 & 0x3f] as char);
+        } else if padding {
+            out.push(PADDING);
+        }
+    }
+    out
+}
+
+/// Encode to Base64 string with the standard alphabet and padding (RFC 4648 section 4).
+pub fn encode(bytes: &[u8]) -> String {
+    encode_with(bytes, ALPHABET_STANDARD, true)
+}
+
+/// Encode to Base64 string with the URL and filename safe alphabet (`-` and `_`) without padding
+/// (RFC 4648 section 5).
+pub fn encode_url_safe(bytes: &[u8]) -> String {
+    encode_with(bytes, ALPHABET_URL_SAFE, false)
+}
+
+fn decode_char(c: char) -> Result<u8, ParseError> {
+    match c {
+        'A'..='Z' => Ok(c as u8 - b'A'),
+        'a'..='z' => Ok(c as u8 - b'a' + 26),
+        '0'..='9' => Ok(c as u8 - b'0' + 52),
+        '+' | '-' => Ok(62),
+        '/' | '_' => Ok(63),
+        _ => Err(ParseError::InvalidChar),
+    }
+}
+
+/// Decode Base64 string. This function accepts both the standard and the URL safe alphabet,
+/// with or without padding.
+pub fn decode(s: &str) -> Result<Vec<u8>, ParseError> {
+    let body = s.trim_end_matches(PADDING);
+    let num_padding = s.len() - body.len();
+    if 2 < num_padding || (0 < num_padding && !s.len().is_multiple_of(4)) || body.len() % 4 == 1 {
+        return Err(ParseError::InvalidLength);
+    }
+
+    let digits: Vec<u8> = body.chars().map(decode_char).collect::<Result<Vec<u8>, ParseError>>()?;
+    let mut out: Vec<u8> = Vec::with_capacity(digits.len() * 3 / 4);
+    for chunk in digits.chunks(4) {
+        let d0 = chunk[0];
+        let d1 = chunk[1];
+        out.push(d0 << 2 | d1 >> 4);
+        if let Some(d2) = chunk.get(2) {
+            out.push(d1 << 4 | d2 >> 2);
+            if let Some(d3) = chunk.get(3) {
+                out.push(d2 << 6 | d3);
+            }
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::text::base64::{decode, encode, encode_url_safe};
+    use crate::text::base64::error::ParseError::{InvalidChar, InvalidLength};
+
+    #[test]
+    fn test_encode() {
+        // RFC 4648 section 10
+        assert_eq!("", encode(b""));
+        assert_eq!("Zg==", encode(b"f"));
+        assert_eq!("Zm8=", encode(b"fo"));
+        assert_eq!("Zm9v", encode(b"foo"));
+        assert_eq!("Zm9vYg==", encode(b"foob"));
+        assert_eq!("Zm9vYmE=", encode(b"fooba"));
+        assert_eq!("Zm9vYmFy", encode(b"foobar"));
+
+        assert_eq!("+/8=", encode(&[0xfb, 0xff]));
+    }
+
+    #[test]
+    fn test_encode_url_safe() {
+        assert_eq!("", encode_url_safe(b""));
+        assert_eq!("Zg", encode_url_safe(b"f"));
+        assert_eq!("Zm8", encode_url_safe(b"fo"));
+        assert_eq!("Zm9v", encode_url_safe(b"foo"));
+        assert_eq!("Zm9vYg", encode_url_safe(b"foob"));
+        assert_eq!("Zm9vYmE", encode_url_safe(b"fooba"));
+        assert_eq!("Zm9vYmFy", encode_url_safe(b"foobar"));
+
+        assert_eq!("-_8", encode_url_safe(&[0xfb, 0xff]));
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(Ok(b"".to_vec()), decode(""));
+        assert_eq!(Ok(b"f".to_vec()), decode("Zg=="));
+        assert_eq!(Ok(b"fo".to_vec()), decode("Zm8="));
+        assert_eq!(Ok(b"foo".to_vec()), decode("Zm9v"));
+        assert_eq!(Ok(b"foob".to_vec()), decode("Zm9vYg=="));
+        assert_eq!(Ok(b"fooba".to_vec()), decode("Zm9vYmE="));
+        assert_eq!(Ok(b"foobar".to_vec()), decode("Zm9vYmFy"));
+
+        // URL safe, without padding
+        assert_eq!(Ok(b"f".to_vec()), decode("Zg"));
+        assert_eq!(Ok(b"fooba".to_vec()), decode("Zm9vYmE"));
+        assert_eq!(Ok(vec![0xfb, 0xff]), decode("+/8="));
+        assert_eq!(Ok(vec![0xfb, 0xff]), decode("-_8"));
+
+        assert_eq!(Err(InvalidChar), decode("Zm9v*mFy"));
+        assert_eq!(Err(InvalidChar), decode("Zm=v"));
+        assert_eq!(Err(InvalidLength), decode("Zm9vY"));
+        assert_eq!(Err(InvalidLength), decode("Zg="));
+        assert_eq!(Err(InvalidLength), decode("Z==="));
+        assert_eq!("invalid character", decode("Zm9v*mFy").unwrap_err().to_string());
+        assert_eq!("invalid length", decode("Zm9vY").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let data: Vec<u8> = (0..=255).collect();
+        for len in 0..data.len() {
+            assert_eq!(Ok(data[..len].to_vec()), decode(&encode(&data[..len])));
+            assert_eq!(Ok(data[..len].to_vec()), decode(&encode_url_safe(&data[..len])));
+        }
+    }
+}
@@ -0,0 +1,2 @@
+/// Base64 shares the error type with other codecs in [`crate::text::codec`].
+pub use crate::text::codec::error::ParseError;
@@ -0,0 +1,35 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Error of the binary-to-text decoders like base64 and base32.
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    InvalidChar,
+    InvalidLength,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidChar => write!(f, "invalid character"),
+            ParseError::InvalidLength => write!(f, "invalid length"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use crate::text::codec::error::ParseError;
+
+    #[test]
+    fn test_error() {
+        let e: Box<dyn Error> = Box::new(ParseError::InvalidChar);
+        assert_eq!("invalid character", e.to_string());
+        let e: Box<dyn Error> = Box::new(ParseError::InvalidLength);
+        assert_eq!("invalid length", e.to_string());
+    }
+}
@@ -1,3 +1,72 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+use crate::text::hex::Hex;
+
+/// Whitespace trimming behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrimMode {
+    /// Keep the string as it is.
+    None,
+
+    /// Trim ASCII whitespace (space, tab, line feed, form feed and carriage return).
+    Ascii,
+
+    /// Trim Unicode whitespace (`White_Space` property), including ideographic space (U+3000).
+    Unicode,
+}
+
+/// Trim leading and trailing whitespace of the string by the mode.
+pub fn trim_mode(s: &str, mode: TrimMode) -> &str {
+    match mode {
+        TrimMode::None => s,
+        TrimMode::Ascii => s.trim_matches(|c: char| c.is_ascii_whitespace()),
+        TrimMode::Unicode => s.trim(),
+    }
+}
+
+/// Number of bytes included before and after the offending byte in [`Utf8Report::context`].
+const UTF8_REPORT_CONTEXT: usize = 4;
+
+/// Report of the invalid UTF-8 byte sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Utf8Report {
+    /// Length of the valid UTF-8 prefix, that is the byte offset of the offending byte.
+    pub valid_up_to: usize,
+
+    /// The first byte of the invalid (or incomplete) sequence.
+    pub byte: u8,
+
+    /// Lower hex dump of the bytes around the offending byte, like `6162 [ff] 6364`.
+    pub context: String,
+}
+
+impl fmt::Display for Utf8Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid UTF-8 byte 0x{} at offset {}: {}", self.byte.to_hex_lower(), self.valid_up_to, self.context)
+    }
+}
+
+impl Error for Utf8Report {}
+
+/// Validate the bytes as UTF-8 string.
+/// Returns the report with the offset and surrounding bytes of the first invalid sequence on failure.
+pub fn validate_utf8(bytes: &[u8]) -> Result<&str, Utf8Report> {
+    std::str::from_utf8(bytes).map_err(|e| {
+        let pos = e.valid_up_to();
+        let before = &bytes[pos.saturating_sub(UTF8_REPORT_CONTEXT)..pos];
+        let after = &bytes[pos + 1..(pos + 1 + UTF8_REPORT_CONTEXT).min(bytes.len())];
+        Utf8Report {
+            valid_up_to: pos,
+            byte: bytes[pos],
+            context: format!("{} [{}] {}", before.to_hex_lower(), bytes[pos].to_hex_lower(), after.to_hex_lower())
+                .trim()
+                .to_string(),
+        }
+    })
+}
+
 /// Essential utility functions for str.
 pub trait StringEssential {
     /// Returns substring of this string as valid UTF-8 string.
@@ -6,8 +75,49 @@ pub trait StringEssential {
     /// Returns substring of this string to the end as valid UTF-8 string.
     fn substring_to_end(&self, start: usize) -> Option<&str>;
 
+    /// Returns the character at the character index (not the byte index).
+    /// Returns `None` if the index is out of range.
+    fn char_at(&self, index: usize) -> Option<char>;
+
     /// Count target character
     fn count_char(&self, x: char) -> usize;
+
+    /// Count non-overlapping occurrences of `needle`.
+    /// Returns `0` if `needle` is empty.
+    fn count_str(&self, needle: &str) -> usize;
+
+    /// Split the string at the first occurrence of `sep`, excluding the separator.
+    /// Returns `None` if `sep` is not found.
+    fn split_once_char(&self, sep: char) -> Option<(&str, &str)>;
+
+    /// Split the string at the last occurrence of `sep`, excluding the separator.
+    /// Returns `None` if `sep` is not found.
+    fn rsplit_once_char(&self, sep: char) -> Option<(&str, &str)>;
+
+    /// Truncate the string to `max_chars` characters, and append `…` if the string was cut.
+    /// Returns the string as it is if the string is within `max_chars` characters.
+    fn truncate_chars<'a>(&'a self, max_chars: usize) -> Cow<'a, str>;
+
+    /// Same as [`StringEssential::truncate_chars`] but append given `ellipsis` instead of `…`.
+    /// The ellipsis is not counted in `max_chars`.
+    fn truncate_chars_with<'a>(&'a self, max_chars: usize, ellipsis: &str) -> Cow<'a, str>;
+
+    /// Replace only the `n`-th (0-indexed) non-overlapping occurrence of `needle` with `replacement`.
+    /// Returns the string as it is if there are fewer than `n + 1` occurrences, or `needle` is empty.
+    fn replace_nth<'a>(&'a self, needle: &str, replacement: &str, n: usize) -> Cow<'a, str>;
+
+    /// Returns true if the string starts with `prefix`, ignoring ASCII case.
+    /// Non-ASCII characters are compared exactly, without Unicode case folding.
+    fn starts_with_ignore_ascii_case(&self, prefix: &str) -> bool;
+
+    /// Returns true if the string ends with `suffix`, ignoring ASCII case.
+    /// Non-ASCII characters are compared exactly, without Unicode case folding.
+    fn ends_with_ignore_ascii_case(&self, suffix: &str) -> bool;
+
+    /// Wrap the string into lines of at most `width` characters, breaking at ASCII whitespaces.
+    /// Runs of whitespaces (including line breaks) are collapsed, and words longer than `width` are hard-broken.
+    /// Returns an empty vector if the string has no word. Panics if `width` is 0.
+    fn ascii_word_wrap(&self, width: usize) -> Vec<String>;
 }
 
 impl StringEssential for str {
@@ -35,14 +145,114 @@ impl StringEssential for str {
         }
     }
 
+    fn char_at(&self, index: usize) -> Option<char> {
+        self.chars().nth(index)
+    }
+
     fn count_char(&self, x: char) -> usize {
         self.chars().map(|t| (t == x) as usize).sum()
     }
+
+    fn count_str(&self, needle: &str) -> usize {
+        if needle.is_empty() {
+            0
+        } else {
+            self.matches(needle).count()
+        }
+    }
+
+    fn split_once_char(&self, sep: char) -> Option<(&str, &str)> {
+        let pos = self.find(sep)?;
+        Some((&self[..pos], &self[pos + sep.len_utf8()..]))
+    }
+
+    fn rsplit_once_char(&self, sep: char) -> Option<(&str, &str)> {
+        let pos = self.rfind(sep)?;
+        Some((&self[..pos], &self[pos + sep.len_utf8()..]))
+    }
+
+    fn truncate_chars<'a>(&'a self, max_chars: usize) -> Cow<'a, str> {
+        self.truncate_chars_with(max_chars, "…")
+    }
+
+    fn truncate_chars_with<'a>(&'a self, max_chars: usize, ellipsis: &str) -> Cow<'a, str> {
+        match self.char_indices().nth(max_chars) {
+            Some((pos, _)) => Cow::Owned(self[..pos].to_string() + ellipsis),
+            None => Cow::Borrowed(self),
+        }
+    }
+
+    fn replace_nth<'a>(&'a self, needle: &str, replacement: &str, n: usize) -> Cow<'a, str> {
+        if needle.is_empty() {
+            return Cow::Borrowed(self);
+        }
+        match self.match_indices(needle).nth(n) {
+            Some((pos, _)) => Cow::Owned(format!("{}{}{}", &self[..pos], replacement, &self[pos + needle.len()..])),
+            None => Cow::Borrowed(self),
+        }
+    }
+
+    fn starts_with_ignore_ascii_case(&self, prefix: &str) -> bool {
+        let b = self.as_bytes();
+        prefix.len() <= b.len() && b[..prefix.len()].eq_ignore_ascii_case(prefix.as_bytes())
+    }
+
+    fn ends_with_ignore_ascii_case(&self, suffix: &str) -> bool {
+        let b = self.as_bytes();
+        suffix.len() <= b.len() && b[b.len() - suffix.len()..].eq_ignore_ascii_case(suffix.as_bytes())
+    }
+
+    fn ascii_word_wrap(&self, width: usize) -> Vec<String> {
+        assert!(0 < width, "width must be greater than 0");
+        let mut lines: Vec<String> = Vec::new();
+        let mut line = String::new();
+        let mut line_chars = 0;
+
+        for word in self.split_ascii_whitespace() {
+            let word_chars = word.chars().count();
+            if 0 < line_chars && line_chars + 1 + word_chars <= width {
+                line.push(' ');
+                line.push_str(word);
+                line_chars += 1 + word_chars;
+                continue;
+            }
+            if 0 < line_chars {
+                lines.push(std::mem::take(&mut line));
+            }
+
+            let chars: Vec<char> = word.chars().collect();
+            let mut chunks = chars.chunks(width).peekable();
+            while let Some(chunk) = chunks.next() {
+                if chunks.peek().is_some() {
+                    lines.push(chunk.iter().collect());
+                } else {
+                    line = chunk.iter().collect();
+                    line_chars = chunk.len();
+                }
+            }
+        }
+        if 0 < line_chars {
+            lines.push(line);
+        }
+        lines
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::text::essential::StringEssential;
+    use std::borrow::Cow;
+    use crate::text::essential::{trim_mode, validate_utf8, StringEssential, TrimMode};
+
+    #[test]
+    fn test_trim_mode() {
+        assert_eq!(" Hello\t", trim_mode(" Hello\t", TrimMode::None));
+        assert_eq!("Hello", trim_mode(" Hello\t", TrimMode::Ascii));
+        assert_eq!("Hello", trim_mode(" Hello\t", TrimMode::Unicode));
+
+        assert_eq!("\u{3000}Hello", trim_mode("\u{3000}Hello ", TrimMode::Ascii));
+        assert_eq!("Hello", trim_mode("\u{3000}Hello ", TrimMode::Unicode));
+        assert_eq!("", trim_mode(" \u{3000} ", TrimMode::Unicode));
+    }
 
     #[test]
     fn test_substring() {
@@ -66,10 +276,134 @@ mod tests {
         assert_eq!(None, "HelloWorld".substring_to_end(11));
     }
 
+    #[test]
+    fn test_char_at() {
+        assert_eq!(Some('é'), "héllo".char_at(1));
+        assert_eq!(Some('h'), "héllo".char_at(0));
+        assert_eq!(Some('o'), "héllo".char_at(4));
+        assert_eq!(None, "héllo".char_at(5));
+        assert_eq!(None, "abc".char_at(5));
+        assert_eq!(Some('🍣'), "今日は🍣と🍶".char_at(3));
+        assert_eq!(None, "".char_at(0));
+    }
+
     #[test]
     fn test_count_char() {
         assert_eq!("Hello World".count_char('o'), 2);
         assert_eq!("Hello World".count_char('O'), 0);
         assert_eq!("Hello World".count_char('H'), 1);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_count_str() {
+        assert_eq!("aaaa".count_str("aa"), 2);
+        assert_eq!("aaa".count_str("aa"), 1);
+        assert_eq!("abcabc".count_str("abc"), 2);
+        assert_eq!("https://example.com//path".count_str("//"), 2);
+        assert_eq!("こんにちは、こんにちは".count_str("にち"), 2);
+        assert_eq!("abc".count_str("x"), 0);
+        assert_eq!("x".count_str(""), 0);
+    }
+
+    #[test]
+    fn test_split_once_char() {
+        assert_eq!(Some(("a", "b=c")), "a=b=c".split_once_char('='));
+        assert_eq!(Some(("", "")), "=".split_once_char('='));
+        assert_eq!(Some(("名前", "値＝値")), "名前＝値＝値".split_once_char('＝'));
+        assert_eq!(None, "abc".split_once_char('='));
+    }
+
+    #[test]
+    fn test_rsplit_once_char() {
+        assert_eq!(Some(("a=b", "c")), "a=b=c".rsplit_once_char('='));
+        assert_eq!(Some(("名前＝値", "値")), "名前＝値＝値".rsplit_once_char('＝'));
+        assert_eq!(Some(("🍣", "🍶")), "🍣🍺🍶".rsplit_once_char('🍺'));
+        assert_eq!(None, "abc".rsplit_once_char('='));
+    }
+
+    #[test]
+    fn test_truncate_chars() {
+        assert!(matches!("Hello".truncate_chars(5), Cow::Borrowed("Hello")));
+        assert!(matches!("Hello".truncate_chars(10), Cow::Borrowed("Hello")));
+        assert_eq!("Hell…", "Hello".truncate_chars(4));
+        assert_eq!("…", "Hello".truncate_chars(0));
+        assert_eq!("", "".truncate_chars(0));
+        assert_eq!("こんにちは…", "こんにちは世界".truncate_chars(5));
+        assert_eq!("こんにちは世界", "こんにちは世界".truncate_chars(7));
+        assert_eq!("今日は🍣…", "今日は🍣と🍶".truncate_chars(4));
+    }
+
+    #[test]
+    fn test_truncate_chars_with() {
+        assert_eq!("Hel...", "Hello".truncate_chars_with(3, "..."));
+        assert_eq!("Hello", "Hello".truncate_chars_with(5, "..."));
+        assert_eq!("世", "世界".truncate_chars_with(1, ""));
+    }
+
+    #[test]
+    fn test_replace_nth() {
+        assert_eq!("banXna", "banana".replace_nth("a", "X", 1));
+        assert_eq!("Xanana", "banana".replace_nth("b", "X", 0));
+        assert_eq!("banan!", "banana".replace_nth("a", "!", 2));
+        assert_eq!("aaXa", "aaaaa".replace_nth("aa", "X", 1));
+        assert_eq!("こんにちは、世界", "こんにちは、こんにちは".replace_nth("こんにちは", "世界", 1));
+        assert!(matches!("banana".replace_nth("a", "X", 3), Cow::Borrowed("banana")));
+        assert!(matches!("banana".replace_nth("x", "X", 0), Cow::Borrowed("banana")));
+        assert!(matches!("banana".replace_nth("", "X", 0), Cow::Borrowed("banana")));
+    }
+
+    #[test]
+    fn test_starts_with_ignore_ascii_case() {
+        assert!("HELLO world".starts_with_ignore_ascii_case("hello"));
+        assert!("HTTP/1.1".starts_with_ignore_ascii_case("http/"));
+        assert!("abc".starts_with_ignore_ascii_case(""));
+        assert!(!"abc".starts_with_ignore_ascii_case("abcd"));
+        assert!(!"ÄBC".starts_with_ignore_ascii_case("äbc"));
+        assert!("ÄBC".starts_with_ignore_ascii_case("Äb"));
+        assert!(!"äbc".starts_with_ignore_ascii_case("a"));
+    }
+
+    #[test]
+    fn test_ends_with_ignore_ascii_case() {
+        assert!("README.MD".ends_with_ignore_ascii_case(".md"));
+        assert!("abc".ends_with_ignore_ascii_case(""));
+        assert!(!"bc".ends_with_ignore_ascii_case("abc"));
+        assert!(!"ABÄ".ends_with_ignore_ascii_case("bä"));
+        assert!("ABÄ".ends_with_ignore_ascii_case("bÄ"));
+    }
+
+    #[test]
+    fn test_ascii_word_wrap() {
+        assert_eq!(vec!["The quick", "brown fox", "jumps over", "the lazy", "dog."],
+                   "The quick brown fox jumps over the lazy dog.".ascii_word_wrap(10));
+        assert_eq!(vec!["Supercalif", "ragilistic", "expialidoc", "ious"],
+                   "Supercalifragilisticexpialidocious".ascii_word_wrap(10));
+        assert_eq!(vec!["a", "abcde", "fg b"], "a abcdefg b".ascii_word_wrap(5));
+        assert_eq!(vec!["one two", "three"], "  one \n two\t three  ".ascii_word_wrap(7));
+        assert_eq!(vec!["ラーメ", "ン 麺"], "ラーメン 麺".ascii_word_wrap(3));
+        assert!("   ".ascii_word_wrap(10).is_empty());
+    }
+
+    #[test]
+    fn test_validate_utf8() {
+        assert_eq!(Ok("Hello"), validate_utf8(b"Hello"));
+        assert_eq!(Ok("こんにちは"), validate_utf8("こんにちは".as_bytes()));
+        assert_eq!(Ok(""), validate_utf8(b""));
+
+        // `こ` (e3 81 93) followed by broken `ん` (e3 82 xx), and an invalid continuation byte
+        let r = validate_utf8(b"abcdef\xe3\x81\x93\xe3\x82\x41gh").unwrap_err();
+        assert_eq!(9, r.valid_up_to);
+        assert_eq!(0xe3, r.byte);
+        assert_eq!("66e38193 [e3] 82416768", r.context);
+        assert_eq!("invalid UTF-8 byte 0xe3 at offset 9: 66e38193 [e3] 82416768", r.to_string());
+
+        let r = validate_utf8(b"\xffabc").unwrap_err();
+        assert_eq!(0, r.valid_up_to);
+        assert_eq!("[ff] 616263", r.context);
+
+        // incomplete sequence at the end
+        let r = validate_utf8(b"ab\xe3\x81").unwrap_err();
+        assert_eq!(2, r.valid_up_to);
+        assert_eq!("6162 [e3] 81", r.context);
+    }
+}
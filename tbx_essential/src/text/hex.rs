@@ -1,6 +1,7 @@
 pub mod error;
 
 use std::borrow::Cow;
+use std::io;
 use crate::number::byte::Bytes;
 use crate::text::hex::error::ParseError;
 
@@ -89,6 +90,8 @@ mod test_parse {
         assert_eq!(Ok(vec![0x12, 0x34, 0xab, 0xef]), parse("1234abef"));
         assert_eq!(Err(InvalidChar), parse("1234____"));
         assert_eq!(Err(LackOfPair), parse("123"));
+        assert_eq!("invalid character", parse("1234____").unwrap_err().to_string());
+        assert_eq!("lack of pair", parse("123").unwrap_err().to_string());
     }
 }
 
@@ -106,6 +109,52 @@ fn to_hex_upper(x: u8) -> Vec<char> {
     vec!(HEX_UPPER[h], HEX_UPPER[l])
 }
 
+fn write_hex<W: io::Write>(w: &mut W, bytes: &[u8], table: &[char; 16]) -> io::Result<()> {
+    // write by chunks to avoid one syscall per byte on the unbuffered writer
+    let mut buf = [0u8; 512];
+    for chunk in bytes.chunks(buf.len() / 2) {
+        for (i, x) in chunk.iter().enumerate() {
+            let (h, l) = to_indices(*x);
+            buf[i * 2] = table[h] as u8;
+            buf[i * 2 + 1] = table[l] as u8;
+        }
+        w.write_all(&buf[..chunk.len() * 2])?;
+    }
+    Ok(())
+}
+
+/// Write lower hex string like `01ab` of the bytes, without building an intermediate string.
+pub fn write_hex_lower<W: io::Write>(w: &mut W, bytes: &[u8]) -> io::Result<()> {
+    write_hex(w, bytes, &HEX_LOWER)
+}
+
+/// Write upper hex string like `01AB` of the bytes, without building an intermediate string.
+pub fn write_hex_upper<W: io::Write>(w: &mut W, bytes: &[u8]) -> io::Result<()> {
+    write_hex(w, bytes, &HEX_UPPER)
+}
+
+#[cfg(test)]
+mod tests_write_hex {
+    use crate::text::hex::{write_hex_lower, write_hex_upper, Hex};
+
+    #[test]
+    fn test_write_hex() {
+        let data: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+
+        let mut lower: Vec<u8> = Vec::new();
+        write_hex_lower(&mut lower, &data).unwrap();
+        assert_eq!(data.to_hex_lower().as_bytes(), lower.as_slice());
+
+        let mut upper: Vec<u8> = Vec::new();
+        write_hex_upper(&mut upper, &data).unwrap();
+        assert_eq!(data.to_hex_upper().as_bytes(), upper.as_slice());
+
+        let mut empty: Vec<u8> = Vec::new();
+        write_hex_lower(&mut empty, &[]).unwrap();
+        assert!(empty.is_empty());
+    }
+}
+
 impl Hex<u8> for u8 {
     fn to_hex_lower<'a>(&self) -> Cow<'a, str> {
         Cow::Owned(to_hex_lower(*self).iter().collect())
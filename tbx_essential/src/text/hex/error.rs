@@ -1,5 +1,34 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+
 #[derive(Debug, PartialEq)]
 pub enum ParseError {
     InvalidChar,
     LackOfPair,
-}
\ No newline at end of file
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidChar => write!(f, "invalid character"),
+            ParseError::LackOfPair => write!(f, "lack of pair"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use crate::text::hex::error::ParseError;
+
+    #[test]
+    fn test_error() {
+        let e: Box<dyn Error> = Box::new(ParseError::InvalidChar);
+        assert_eq!("invalid character", e.to_string());
+        let e: Box<dyn Error> = Box::new(ParseError::LackOfPair);
+        assert_eq!("lack of pair", e.to_string());
+    }
+}
@@ -1,23 +1,31 @@
 use std::borrow::Cow;
 
 use crate::text::essential::StringEssential;
-use crate::text::token::ascii::AsciiTokenizer;
+use crate::text::token::ascii::{join_as, AsciiTokenizer, Case};
 
 pub trait Ascii {
-    /// Convert string to CamelCase (upper case).
+    /// Convert string to CamelCase (upper case), also known as PascalCase.
     /// Non ASCII alphabet or number characters are ignored.
     /// This function returns always upper case for the first char of the first token.
     /// Returns empty string if no ASCII alphabet/number character in given string.
     /// Example: "camel case" -> "CamelCase".
+    /// See also [`Ascii::to_ascii_pascal`], the same conversion under the conventional name.
     fn to_ascii_camel_upper<'a>(&self) -> Cow<'a, str>;
 
-    /// Convert string to CamelCase (lower case).
+    /// Convert string to camelCase (lower case). This is the conventional "camelCase".
     /// Non ASCII alphabet or number characters are ignored.
     /// This function returns always lower case for the first char of the first token.
     /// Returns empty string if no ASCII alphabet/number character in given string.
     /// Example: "camel case" -> "camelCase".
     fn to_ascii_camel_lower<'a>(&self) -> Cow<'a, str>;
 
+    /// Convert string to PascalCase (upper case for the first char of the first token).
+    /// This is an alias of [`Ascii::to_ascii_camel_upper`].
+    /// Example: "pascal case" -> "PascalCase".
+    fn to_ascii_pascal<'a>(&self) -> Cow<'a, str> {
+        self.to_ascii_camel_upper()
+    }
+
     /// Convert string to KEBAB-CASE (all capital).
     /// Non ASCII alphabet or number characters are ignored.
     /// Returns empty string if no ASCII alphabet/number character in given string.
@@ -53,12 +61,17 @@ pub trait Ascii {
     /// Returns empty string if no ASCII alphabet/number character in given string.
     /// Example: "Snake case" -> "snake_case".
     fn to_ascii_snake_lower<'a>(&self) -> Cow<'a, str>;
+
+    /// Collapse runs of non ASCII alphabet or number characters into a single space.
+    /// Leading and trailing runs are removed.
+    /// Example: "user__name--id" -> "user name id".
+    fn collapse_separators<'a>(&self) -> Cow<'a, str>;
 }
 
 
 impl Ascii for str {
     fn to_ascii_camel_upper<'a>(&self) -> Cow<'a, str> {
-        Cow::Owned(self.tokenize_ascii_alpha_num_to_first_upper().join(""))
+        Cow::Owned(join_as(&self.tokenize_ascii_alpha_num(), "", Case::FirstUpper))
     }
 
     fn to_ascii_camel_lower<'a>(&self) -> Cow<'a, str> {
@@ -71,27 +84,34 @@ impl Ascii for str {
     }
 
     fn to_ascii_kebab_capital<'a>(&self) -> Cow<'a, str> {
-        Cow::Owned(self.tokenize_ascii_alpha_num_to_capital().join("-"))
+        Cow::Owned(join_as(&self.tokenize_ascii_alpha_num(), "-", Case::Capital))
     }
 
     fn to_ascii_kebab_upper<'a>(&self) -> Cow<'a, str> {
-        Cow::Owned(self.tokenize_ascii_alpha_num_to_first_upper().join("-"))
+        Cow::Owned(join_as(&self.tokenize_ascii_alpha_num(), "-", Case::FirstUpper))
     }
 
     fn to_ascii_kebab_lower<'a>(&self) -> Cow<'a, str> {
-        Cow::Owned(self.tokenize_ascii_alpha_num_to_lower().join("-"))
+        Cow::Owned(join_as(&self.tokenize_ascii_alpha_num(), "-", Case::Lower))
     }
 
     fn to_ascii_snake_capital<'a>(&self) -> Cow<'a, str> {
-        Cow::Owned(self.tokenize_ascii_alpha_num_to_capital().join("_"))
+        Cow::Owned(join_as(&self.tokenize_ascii_alpha_num(), "_", Case::Capital))
     }
 
     fn to_ascii_snake_upper<'a>(&self) -> Cow<'a, str> {
-        Cow::Owned(self.tokenize_ascii_alpha_num_to_first_upper().join("_"))
+        Cow::Owned(join_as(&self.tokenize_ascii_alpha_num(), "_", Case::FirstUpper))
     }
 
     fn to_ascii_snake_lower<'a>(&self) -> Cow<'a, str> {
-        Cow::Owned(self.tokenize_ascii_alpha_num_to_lower().join("_"))
+        Cow::Owned(join_as(&self.tokenize_ascii_alpha_num(), "_", Case::Lower))
+    }
+
+    fn collapse_separators<'a>(&self) -> Cow<'a, str> {
+        let words: Vec<&str> = self.split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .collect();
+        Cow::Owned(words.join(" "))
     }
 }
 
@@ -123,6 +143,14 @@ mod tests {
         assert_eq!("c3P0", " c***3***p***0".to_ascii_camel_lower());
     }
 
+    #[test]
+    fn test_to_ascii_pascal() {
+        assert_eq!("PascalCase", "pascal case".to_ascii_pascal());
+        for s in ["camel Case", "CAMEL-case", "-CAMEL-CASE-", "*", "c", " c***3***p***0", "user__name--id"] {
+            assert_eq!(s.to_ascii_camel_upper(), s.to_ascii_pascal());
+        }
+    }
+
     #[test]
     fn test_to_ascii_kebab_capital() {
         assert_eq!("KEBAB-CAPITAL", "kebab Capital".to_ascii_kebab_capital());
@@ -170,4 +198,16 @@ mod tests {
         assert_eq!("snake_lower", "-Snake-Lower-".to_ascii_snake_lower());
         assert_eq!("snake_lower", "=snake=Lower=".to_ascii_snake_lower());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_collapse_separators() {
+        assert_eq!("user name", "user__name".collapse_separators());
+        assert_eq!("user name", "user--name".collapse_separators());
+        assert_eq!("user name id", "__user__name--id--".collapse_separators());
+        assert_eq!("UserName", "UserName".collapse_separators());
+        assert_eq!("", "*-*".collapse_separators());
+
+        assert_eq!("user_name", "user__name".collapse_separators().to_ascii_snake_lower());
+        assert_eq!("user_name", "user--name".collapse_separators().to_ascii_snake_lower());
+    }
+}
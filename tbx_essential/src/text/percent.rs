@@ -0,0 +1,85 @@
+pub mod error;
+
+use crate::text::hex;
+use crate::text::hex::Hex;
+use crate::text::percent::error::ParseError;
+
+/// Returns true if the byte is in the unreserved set of RFC 3986 (`A-Za-z0-9-._~`).
+fn is_unreserved(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'-' || b == b'.' || b == b'_' || b == b'~'
+}
+
+/// Encode to percent-encoded string (RFC 3986 section 2.1).
+/// All bytes of the UTF-8 representation outside the unreserved set are encoded as `%XX` in upper case hex.
+/// Example: `"a b/c"` -> `"a%20b%2Fc"`.
+pub fn encode(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    for b in input.bytes() {
+        if is_unreserved(b) {
+            out.push(b as char);
+        } else {
+            out.push('%');
+            out.push_str(&b.to_hex_upper());
+        }
+    }
+    out
+}
+
+/// Decode percent-encoded string. Hex digits of the escape are case-insensitive.
+/// Returns an error if the escape is not followed by two hex digits, or decoded bytes are not valid UTF-8.
+pub fn decode(input: &str) -> Result<String, ParseError> {
+    let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+    let mut i = 0;
+    let raw = input.as_bytes();
+    while i < raw.len() {
+        if raw[i] == b'%' {
+            match input.get(i + 1..i + 3).map(hex::parse) {
+                Some(Ok(b)) => bytes.extend(b),
+                _ => return Err(ParseError::InvalidEscape),
+            }
+            i += 3;
+        } else {
+            bytes.push(raw[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| ParseError::InvalidUtf8)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::text::percent::{decode, encode};
+    use crate::text::percent::error::ParseError;
+
+    #[test]
+    fn test_encode() {
+        assert_eq!("a%20b%2Fc", encode("a b/c"));
+        assert_eq!("AZaz09-._~", encode("AZaz09-._~"));
+        assert_eq!("%3F%26%3D%2B%25", encode("?&=+%"));
+        assert_eq!("%E3%83%A9%E3%83%BC%E3%83%A1%E3%83%B3", encode("ラーメン"));
+        assert_eq!("", encode(""));
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(Ok("a b/c".to_string()), decode("a%20b%2Fc"));
+        assert_eq!(Ok("a b/c".to_string()), decode("a%20b%2fc"));
+        assert_eq!(Ok("a+b".to_string()), decode("a+b"));
+        assert_eq!(Ok("ラーメン".to_string()), decode("%E3%83%A9%E3%83%BC%E3%83%A1%E3%83%B3"));
+
+        assert_eq!(Err(ParseError::InvalidEscape), decode("100%"));
+        assert_eq!(Err(ParseError::InvalidEscape), decode("%2"));
+        assert_eq!(Err(ParseError::InvalidEscape), decode("%zz"));
+        assert_eq!(Err(ParseError::InvalidEscape), decode("%aラ"));
+        assert_eq!(Err(ParseError::InvalidUtf8), decode("%E3%83"));
+        assert_eq!("invalid percent escape", decode("%zz").unwrap_err().to_string());
+        assert_eq!("invalid UTF-8 sequence", decode("%E3%83").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for s in ["ラーメン 123 / Udon?", "日本語 & English = 😀", "~user.name_1-2", "%41"] {
+            assert_eq!(s, decode(&encode(s)).unwrap());
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+
+#[derive(Debug, PartialEq)]
+pub enum ParseError {
+    InvalidEscape,
+    InvalidUtf8,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidEscape => write!(f, "invalid percent escape"),
+            ParseError::InvalidUtf8 => write!(f, "invalid UTF-8 sequence"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use crate::text::percent::error::ParseError;
+
+    #[test]
+    fn test_error() {
+        let e: Box<dyn Error> = Box::new(ParseError::InvalidEscape);
+        assert_eq!("invalid percent escape", e.to_string());
+        let e: Box<dyn Error> = Box::new(ParseError::InvalidUtf8);
+        assert_eq!("invalid UTF-8 sequence", e.to_string());
+    }
+}
@@ -0,0 +1,50 @@
+const FILLED: char = '#';
+const EMPTY: char = '-';
+
+/// Render progress bar string like `[####----] 50%`.
+/// The bar has `width` characters between brackets, and the percentage is rounded down.
+/// `current` greater than `total` is clamped to `total`.
+/// If `total` is zero, the progress is treated as complete (`[########] 100%`),
+/// since there is nothing left to do.
+pub fn progress_bar(current: u64, total: u64, width: usize) -> String {
+    let (current, total) = if total == 0 {
+        (1, 1)
+    } else {
+        (current.min(total), total)
+    };
+    let filled = (current as u128 * width as u128 / total as u128) as usize;
+    let percent = current as u128 * 100 / total as u128;
+
+    let mut out = String::with_capacity(width + 7);
+    out.push('[');
+    out.extend(std::iter::repeat_n(FILLED, filled));
+    out.extend(std::iter::repeat_n(EMPTY, width - filled));
+    out.push_str(&format!("] {}%", percent));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::text::progress::progress_bar;
+
+    #[test]
+    fn test_progress_bar() {
+        assert_eq!("[--------] 0%", progress_bar(0, 10, 8));
+        assert_eq!("[####----] 50%", progress_bar(5, 10, 8));
+        assert_eq!("[########] 100%", progress_bar(10, 10, 8));
+        assert_eq!("[##------] 33%", progress_bar(1, 3, 8));
+        assert_eq!("[] 50%", progress_bar(1, 2, 0));
+        assert_eq!("[####] 100%", progress_bar(u64::MAX, u64::MAX, 4));
+    }
+
+    #[test]
+    fn test_progress_bar_clamp() {
+        assert_eq!("[########] 100%", progress_bar(20, 10, 8));
+    }
+
+    #[test]
+    fn test_progress_bar_zero_total() {
+        assert_eq!("[########] 100%", progress_bar(0, 0, 8));
+        assert_eq!("[########] 100%", progress_bar(3, 0, 8));
+    }
+}
@@ -0,0 +1,70 @@
+use std::borrow::Cow;
+use crate::text::percent;
+
+/// Percent-decode the component. Returns the component as it is if it is not decodable.
+fn decode_component(s: &str) -> Cow<'_, str> {
+    match percent::decode(s) {
+        Ok(d) => Cow::Owned(d),
+        Err(_) => Cow::Borrowed(s),
+    }
+}
+
+/// Parse query string like `a=1&b=two%20words` into the list of key and value pairs in order.
+/// Keys and values are percent-decoded, or kept as it is if the decoding fails.
+/// A key without `=` has an empty value, and empty segments (e.g. `a=1&&b=2`) are skipped.
+/// `+` is not treated as a space.
+pub fn parse(qs: &str) -> Vec<(String, String)> {
+    qs.split('&')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| {
+            let (k, v) = segment.split_once('=').unwrap_or((segment, ""));
+            (decode_component(k).into_owned(), decode_component(v).into_owned())
+        })
+        .collect()
+}
+
+/// Build query string from the list of key and value pairs. Inverse of [`parse`].
+/// Keys and values are percent-encoded, then joined like `a=1&b=two%20words`.
+pub fn build(pairs: &[(String, String)]) -> String {
+    pairs.iter()
+        .map(|(k, v)| format!("{}={}", percent::encode(k), percent::encode(v)))
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::text::query::{build, parse};
+
+    fn pairs(p: &[(&str, &str)]) -> Vec<(String, String)> {
+        p.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(pairs(&[("a", "1"), ("b", "two words")]), parse("a=1&b=two%20words"));
+        assert_eq!(pairs(&[("flag", ""), ("x", "")]), parse("flag&x="));
+        assert_eq!(pairs(&[("a", "1"), ("b", "2=3")]), parse("a=1&&b=2=3&"));
+        assert_eq!(pairs(&[("q", "100%"), ("a+b", "c")]), parse("q=100%&a+b=c"));
+        assert!(parse("").is_empty());
+    }
+
+    #[test]
+    fn test_build() {
+        assert_eq!("a=1&b=two%20words", build(&pairs(&[("a", "1"), ("b", "two words")])));
+        assert_eq!("flag=", build(&pairs(&[("flag", "")])));
+        assert_eq!("", build(&[]));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let p = pairs(&[
+            ("name", "Ramen & Udon"),
+            ("path", "/a/b?c=d#e"),
+            ("math", "1+1=2"),
+            ("日本語", "ラーメン 100%"),
+            ("empty", ""),
+        ]);
+        assert_eq!(p, parse(&build(&p)));
+    }
+}
@@ -1,2 +1,3 @@
 pub mod ascii;
+pub mod token;
 
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::ops::RangeInclusive;
 
 use crate::number::random::{Generator, Random};
 
@@ -13,82 +14,71 @@ pub fn next<'a>(length: usize, chars: Vec<char>) -> Cow<'a, str> {
     Cow::Owned((0..length).map(|_i| *chars.get(r.next_range_usize(0..num_chars)).unwrap_or(&alt_char)).collect())
 }
 
+/// Generate random string of length, by using chars in given ranges like `['a'..='f', '0'..='9']`.
+/// Characters that appear in multiple ranges are more likely to be chosen.
+pub fn next_from_ranges<'a>(length: usize, ranges: &[RangeInclusive<char>]) -> Cow<'a, str> {
+    next(length, ranges.iter().flat_map(|r| r.clone()).collect())
+}
+
 /// Generate random ASCII numeric string of length.
 pub fn next_numeric<'a>(length: usize) -> Cow<'a, str> {
-    next(length, vec![
-        '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
-    ])
+    next_from_ranges(length, &['0'..='9'])
 }
 
 /// Generate random ASCII hex-numeric string (upper case) of length.
 pub fn next_hex_upper<'a>(length: usize) -> Cow<'a, str> {
-    next(length, vec![
-        '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
-        'A', 'B', 'C', 'D', 'E', 'F',
-    ])
+    next_from_ranges(length, &['0'..='9', 'A'..='F'])
 }
 
 /// Generate random ASCII hex-numeric string (lower case) of length.
 pub fn next_hex_lower<'a>(length: usize) -> Cow<'a, str> {
-    next(length, vec![
-        '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
-        'a', 'b', 'c', 'd', 'e', 'f',
-    ])
+    next_from_ranges(length, &['0'..='9', 'a'..='f'])
 }
 
 /// Generate random ASCII upper case alphabet string of length.
 pub fn next_alphabet_upper<'a>(length: usize) -> Cow<'a, str> {
-    next(length, vec![
-        'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
-        'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
-    ])
+    next_from_ranges(length, &['A'..='Z'])
 }
 
 /// Generate random ASCII lower case alphabet string of length.
 pub fn next_alphabet_lower<'a>(length: usize) -> Cow<'a, str> {
-    next(length, vec![
-        'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
-        'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
-    ])
+    next_from_ranges(length, &['a'..='z'])
 }
 
 /// Generate random ASCII mixed case alphabet string of length.
 pub fn next_alphabet_mixed<'a>(length: usize) -> Cow<'a, str> {
-    next(length, vec![
-        'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
-        'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
-        'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
-        'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
-    ])
+    next_from_ranges(length, &['a'..='z', 'A'..='Z'])
 }
 
 /// Generate random ASCII upper case alpha-numeric string of length.
 pub fn next_alpha_numeric_upper<'a>(length: usize) -> Cow<'a, str> {
-    next(length, vec![
-        '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
-        'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
-        'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
-    ])
+    next_from_ranges(length, &['0'..='9', 'A'..='Z'])
 }
 
 /// Generate random ASCII lower case alpha-numeric string of length.
 pub fn next_alpha_numeric_lower<'a>(length: usize) -> Cow<'a, str> {
-    next(length, vec![
-        '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
-        'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
-        'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
-    ])
+    next_from_ranges(length, &['0'..='9', 'a'..='z'])
 }
 
 /// Generate random ASCII mixed case alpha-numeric string of length.
 pub fn next_alpha_numeric_mixed<'a>(length: usize) -> Cow<'a, str> {
-    next(length, vec![
-        '0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
-        'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
-        'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
-        'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
-        'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
-    ])
+    next_from_ranges(length, &['0'..='9', 'a'..='z', 'A'..='Z'])
+}
+
+/// Characters easily confused with each other, like `0` and `O`, or `1`, `l` and `I`.
+const AMBIGUOUS_CHARS: [char; 5] = ['0', 'O', '1', 'l', 'I'];
+
+/// Generate random string of length, by using given chars except `exclude`.
+/// Panics if no character remains after the exclusion.
+pub fn next_excluding<'a>(length: usize, chars: Vec<char>, exclude: &[char]) -> Cow<'a, str> {
+    next(length, chars.into_iter().filter(|c| !exclude.contains(c)).collect())
+}
+
+/// Generate random ASCII mixed case alpha-numeric string of length,
+/// without ambiguous characters `0`, `O`, `1`, `l` and `I`. Suitable for human-readable passwords.
+pub fn next_unambiguous_alpha_numeric<'a>(length: usize) -> Cow<'a, str> {
+    let chars: Vec<char> = ['0'..='9', 'a'..='z', 'A'..='Z'].into_iter().flatten().collect();
+    next_excluding(length, chars, &AMBIGUOUS_CHARS)
 }
 
 #[cfg(test)]
@@ -165,6 +155,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_next_from_ranges() {
+        let ranges = vec!['a'..='f', '0'..='9'];
+        for i in 1..100 {
+            let q = next_from_ranges(i, &ranges);
+            assert_eq!(q.chars().count(), i);
+            assert!(q.chars().all(|c| ranges.iter().any(|r| r.contains(&c))), "testing string[{}]", q);
+        }
+        assert_eq!(next_from_ranges(3, &['ア'..='ア']), "アアア");
+
+        verify_ascii(
+            ranges,
+            (6 + 10) * 5,
+            |c| next_from_ranges(c, &['a'..='f', '0'..='9']),
+        )
+    }
+
     #[test]
     fn test_next_numeric() {
         verify_next_numeric(0..=9 as usize, next_numeric, |i: usize| -> Cow<str> {
@@ -278,4 +285,32 @@ mod tests {
             next_alpha_numeric_mixed,
         )
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_next_excluding() {
+        for i in 1..100 {
+            let q = next_excluding(i, vec!['a', 'b', 'c', 'd'], &['b', 'd']);
+            assert_eq!(q.chars().count(), i);
+            assert!(q.chars().all(|c| c == 'a' || c == 'c'), "testing string[{}]", q);
+        }
+        assert_eq!(next_excluding(3, vec!['x', 'y'], &['y']), "xxx");
+    }
+
+    #[test]
+    fn test_next_unambiguous_alpha_numeric() {
+        for i in 1..100 {
+            let q = next_unambiguous_alpha_numeric(i);
+            assert_eq!(q.chars().count(), i);
+            assert!(q.chars().all(|c| c.is_ascii_alphanumeric()), "testing string[{}]", q);
+            assert!(!q.contains(['0', 'O', '1', 'l', 'I']), "testing string[{}]", q);
+        }
+        let q = next_unambiguous_alpha_numeric(10_000);
+        assert!(!q.contains(['0', 'O', '1', 'l', 'I']));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_next_excluding_all() {
+        next_excluding(3, vec!['a'], &['a']);
+    }
+}
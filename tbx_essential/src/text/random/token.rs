@@ -0,0 +1,43 @@
+use crate::number::random::{Generator, Random};
+use crate::text::base64::ALPHABET_URL_SAFE;
+
+/// Generate random token like API keys, consists of URL safe Base64 characters (`[A-Za-z0-9_-]`).
+/// Each character has 6 bits of entropy.
+///
+/// The entropy source is [`Random::new_thread_local`], that is [`rand::rngs::ThreadRng`],
+/// a cryptographically secure generator seeded and periodically reseeded from the operating system.
+pub fn new_token(length: usize) -> String {
+    new_token_with_rand(&mut Random::new_thread_local(), length)
+}
+
+/// Generate random token like [`new_token`] with the given generator.
+/// The token is only as unpredictable as the generator.
+pub fn new_token_with_rand<G: Generator>(rand: &mut G, length: usize) -> String {
+    (0..length)
+        .map(|_| ALPHABET_URL_SAFE[rand.next_range_usize(0..ALPHABET_URL_SAFE.len())] as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::number::random::Random;
+    use crate::text::random::token::{new_token, new_token_with_rand};
+
+    #[test]
+    fn test_new_token() {
+        for length in [0, 1, 16, 32, 100] {
+            let t = new_token(length);
+            assert_eq!(length, t.len());
+            assert!(t.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-'), "{}", t);
+        }
+        assert_ne!(new_token(32), new_token(32));
+    }
+
+    #[test]
+    fn test_new_token_with_rand() {
+        let mut r = Random::new_thread_local();
+        let t = new_token_with_rand(&mut r, 43);
+        assert_eq!(43, t.len());
+        assert!(t.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-'), "{}", t);
+    }
+}
@@ -3,7 +3,9 @@ pub mod matcher;
 pub mod splitter;
 
 use std::borrow::Cow;
-use regex::{NoExpand as RegexNoExpand, Regex as RegexRegex};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use regex::{Error as RegexError, NoExpand as RegexNoExpand, Regex as RegexRegex};
 use crate::text::regex::error::ParseError;
 use crate::text::regex::matcher::{CaptureMatches, Captures, Match, Matches};
 use crate::text::regex::splitter::Split;
@@ -20,18 +22,48 @@ pub trait Matcher {
     /// If no match exists, then None is returned.
     fn find_first<'t>(&self, text: &'t str) -> Option<Match<'t>>;
 
+    /// Returns the rightmost of the non-overlapping matches in text.
+    /// If no match exists, then None is returned.
+    fn find_last<'t>(&self, text: &'t str) -> Option<Match<'t>> {
+        self.find_iter(text).last()
+    }
+
     /// Same as [`Matcher::find_first`] but start the search at the given offset.
     fn find_at<'t>(&self, text: &'t str, offset: usize) -> Option<Match<'t>>;
 
+    /// Same as [`Matcher::find_at`] but start the search just after the first occurrence of `delimiter`.
+    /// Returns None if the `delimiter` is not found in text.
+    fn find_after_char<'t>(&self, text: &'t str, delimiter: char) -> Option<Match<'t>> {
+        let pos = text.find(delimiter)?;
+        self.find_at(text, pos + delimiter.len_utf8())
+    }
+
     /// Returns an iterator for each successive non-overlapping match in text,
     /// returning the start and end byte indices with respect to `text`.
     fn find_iter<'r, 't>(&'r self, text: &'t str) -> Matches<'r, 't>;
 
+    /// Returns true when the leftmost-first match spans the entire text (byte range `0..text.len()`).
+    /// Note: leftmost-first semantics may prefer a shorter alternative, e.g. `a|ab` does not fully match `ab`.
+    fn is_full_match(&self, text: &str) -> bool {
+        match self.find_first(text) {
+            Some(m) => m.start() == 0 && m.end() == text.len(),
+            None => false,
+        }
+    }
+
+    /// Returns the number of non-overlapping matches in text.
+    fn match_count(&self, text: &str) -> usize {
+        self.find_iter(text).count()
+    }
+
     /// Returns the capture groups corresponding to the leftmost-first match in text.
     /// Capture group 0 always corresponds to the entire match.
     /// If no match is found, then None is returned.
     fn capture_first<'t>(&self, text: &'t str) -> Option<Captures<'t>>;
 
+    /// Same as [`Matcher::capture_first`] but start the search at the given offset.
+    fn capture_at<'t>(&self, text: &'t str, offset: usize) -> Option<Captures<'t>>;
+
     /// Returns an iterator over all the non-overlapping capture groups matched in text.
     /// This is operationally the same as find_iter,
     /// except it yields information about capturing group matches.
@@ -69,6 +101,11 @@ pub trait Splitter {
 /// it will be difficult to maintain compatibility.
 pub struct Regex {
     re: RegexRegex,
+
+    /// The pattern anchored to the entire text like `\A(?:pattern)\z`, built on the first use.
+    /// The anchored pattern may fail to compile even if the pattern is valid,
+    /// such as a trailing `# comment` of the `(?x)` mode that swallows the closing anchor.
+    re_full: OnceLock<Result<RegexRegex, RegexError>>,
 }
 
 impl Regex {
@@ -77,10 +114,60 @@ impl Regex {
         match RegexRegex::new(re) {
             Ok(rr) => Ok(Self {
                 re: rr,
+                re_full: OnceLock::new(),
             }),
             Err(err) => Err(ParseError::from(err))
         }
     }
+
+    /// Returns the number of capture groups in the regular expression.
+    /// The count includes the implicit group 0 that corresponds to the entire match.
+    pub fn captures_len(&self) -> usize {
+        self.re.captures_len()
+    }
+
+    /// Returns all non-overlapping matches in text.
+    pub fn find_all<'t>(&self, text: &'t str) -> Vec<Match<'t>> {
+        self.find_iter(text).collect()
+    }
+
+    /// Returns at most `limit` substrings of text delimited by a match of the regular expression.
+    /// The last element contains the remainder of text that is not split.
+    /// Returns an empty vector if `limit` is 0.
+    pub fn split_n<'t>(&self, text: &'t str, limit: usize) -> Vec<&'t str> {
+        self.re.splitn(text, limit).collect()
+    }
+
+    /// Returns texts of the named capture groups of the leftmost-first match, keyed by the group name.
+    /// Named groups that did not participate in the match are not included.
+    /// If no match is found, then None is returned.
+    pub fn capture_first_named<'t>(&self, text: &'t str) -> Option<HashMap<String, &'t str>> {
+        let c = self.re.captures(text)?;
+        Some(self.re.capture_names()
+            .flatten()
+            .filter_map(|name| c.name(name).map(|m| (name.to_string(), m.as_str())))
+            .collect())
+    }
+
+    /// Returns the capture groups only when the pattern matches the entire text.
+    /// Otherwise, None is returned. Same as matching with the pattern wrapped like `^(?:pattern)$`,
+    /// then alternations are tried until one spans the entire text (e.g. `a|ab` matches `ab`).
+    /// Unlike [`Matcher::is_full_match`] that tests only the leftmost-first match.
+    ///
+    /// If the anchored pattern cannot be compiled, falls back to the leftmost-first match
+    /// and returns it only when the match spans the entire text.
+    pub fn captures_full<'t>(&self, text: &'t str) -> Option<Captures<'t>> {
+        match self.re_full.get_or_init(|| RegexRegex::new(&format!(r"\A(?:{})\z", self.re.as_str()))) {
+            Ok(re_full) => re_full.captures(text).map(Captures::new),
+            Err(_) => {
+                let c = self.re.captures(text)?;
+                match c.get(0) {
+                    Some(m) if m.start() == 0 && m.end() == text.len() => Some(Captures::new(c)),
+                    _ => None,
+                }
+            }
+        }
+    }
 }
 
 impl Matcher for Regex {
@@ -117,6 +204,10 @@ impl Matcher for Regex {
         }
     }
 
+    fn capture_at<'t>(&self, text: &'t str, offset: usize) -> Option<Captures<'t>> {
+        self.re.captures_at(text, offset).map(Captures::new)
+    }
+
     fn capture_iter<'r, 't>(&'r self, text: &'t str) -> CaptureMatches<'r, 't> {
         CaptureMatches::new(self.re.captures_iter(text))
     }
@@ -124,9 +215,17 @@ impl Matcher for Regex {
 
 #[cfg(test)]
 mod tests_matcher {
+    use std::error::Error;
     use crate::text::regex::{Matcher, Regex};
     use crate::text::regex::matcher::CaptureIndexer;
 
+    #[test]
+    fn test_parse_error() {
+        let e = Regex::parse(r"(\d").err().unwrap();
+        assert!(e.to_string().contains("unclosed group"));
+        assert!(e.source().is_some());
+    }
+
     #[test]
     fn test_is_match() {
         let re = Regex::parse(r"\d{4}").unwrap();
@@ -148,6 +247,32 @@ mod tests_matcher {
         assert!(re.find_at("Rust", 1).is_none());
     }
 
+    #[test]
+    fn test_find_last() {
+        let re = Regex::parse(r"\d+").unwrap();
+
+        let text = "a1b22c333";
+        let m = re.find_last(text).unwrap();
+        assert_eq!(m.as_str(), "333");
+        assert_eq!(m.end(), text.len());
+        assert_eq!(re.find_last("a1b22c").unwrap().range(), 3..5);
+        assert!(re.find_last("abc").is_none());
+
+        let re = Regex::parse(r"[^/]+").unwrap();
+        assert_eq!(re.find_last("/usr/local/bin").unwrap().as_str(), "bin");
+    }
+
+    #[test]
+    fn test_find_after_char() {
+        let re = Regex::parse(r"\d+").unwrap();
+
+        assert_eq!(re.find_after_char("skip:then 123", ':').unwrap().as_str(), "123");
+        assert_eq!(re.find_after_char("7 skip:then 123", ':').unwrap().range(), 12..15);
+        assert_eq!(re.find_after_char("1：23", '：').unwrap().as_str(), "23");
+        assert!(re.find_after_char("skip then 123", ':').is_none());
+        assert!(re.find_after_char("123:none", ':').is_none());
+    }
+
     #[test]
     fn test_find_iter() {
         let re = Regex::parse(r"\d{4}").unwrap();
@@ -156,6 +281,40 @@ mod tests_matcher {
         assert_eq!(re.find_iter("2022-2023-2024").nth(1).unwrap().as_str(), "2023");
     }
 
+    #[test]
+    fn test_is_full_match() {
+        let re = Regex::parse(r"\d+").unwrap();
+
+        assert!(re.is_full_match("123"));
+        assert!(!re.is_full_match("1a2"));
+        assert!(!re.is_full_match("12 "));
+        assert!(!re.is_full_match(" 12"));
+        assert!(!re.is_full_match(""));
+
+        let re = Regex::parse(r"(?m)^\w+$").unwrap();
+        assert!(re.is_full_match("line"));
+        assert!(!re.is_full_match("line1\nline2"));
+    }
+
+    #[test]
+    fn test_match_count() {
+        let re = Regex::parse(r"\d+").unwrap();
+
+        assert_eq!(re.match_count("1 22 333"), 3);
+        assert_eq!(re.match_count("no digits"), 0);
+    }
+
+    #[test]
+    fn test_find_all() {
+        let re = Regex::parse(r"\d+").unwrap();
+
+        let all = re.find_all("1 22 333");
+        assert_eq!(all.len(), 3);
+        assert_eq!(all.iter().map(|m| m.as_str()).collect::<Vec<&str>>(), vec!["1", "22", "333"]);
+        assert_eq!(all[2].range(), 5..8);
+        assert!(re.find_all("no digits").is_empty());
+    }
+
     #[test]
     fn test_captures() {
         let re = Regex::parse(r"(\d{4})-(\d{2})-(\d{2})").unwrap();
@@ -167,6 +326,43 @@ mod tests_matcher {
         assert_eq!(da.get(3).unwrap().as_str(), "27");
     }
 
+    #[test]
+    fn test_all_groups() {
+        let re = Regex::parse(r"(\d{4})-(\d{2})?-(\d{2})").unwrap();
+
+        let c = re.capture_first("2022--27").unwrap();
+        assert_eq!(vec![Some("2022"), None, Some("27")], c.all_groups());
+
+        let c = re.capture_first("date: 2022-12-27").unwrap();
+        assert_eq!(vec![Some("2022"), Some("12"), Some("27")], c.all_groups());
+
+        let re = Regex::parse(r"\d+").unwrap();
+        assert!(re.capture_first("123").unwrap().all_groups().is_empty());
+    }
+
+    #[test]
+    fn test_captures_len() {
+        let re = Regex::parse(r"(\d{4})-(\d{2})").unwrap();
+        assert_eq!(re.captures_len(), 3);
+
+        let re_no_group = Regex::parse(r"\d{4}").unwrap();
+        assert_eq!(re_no_group.captures_len(), 1);
+    }
+
+    #[test]
+    fn test_capture_at() {
+        let re = Regex::parse(r"(\d{4})-(\d{2})-(\d{2})").unwrap();
+        let text = "Departure: 2022-12-27, Arrival: 2022-12-28";
+
+        let da = re.capture_at(text, 12).unwrap();
+        assert_eq!(da.get(0).unwrap().as_str(), "2022-12-28");
+        assert_eq!(da.get(0).unwrap().start(), 32);
+        assert_eq!(da.get(3).unwrap().as_str(), "28");
+
+        assert_eq!(re.capture_at(text, 0).unwrap().get(3).unwrap().as_str(), "27");
+        assert!(re.capture_at(text, 33).is_none());
+    }
+
     #[test]
     fn test_captures_name() {
         let re = Regex::parse(r"(?P<year>\d{4})-(?P<month>\d{2})-(?P<day>\d{2})").unwrap();
@@ -178,6 +374,53 @@ mod tests_matcher {
         assert_eq!(da.get("day").unwrap().as_str(), "27");
     }
 
+    #[test]
+    fn test_capture_first_named() {
+        let re = Regex::parse(r"(?P<k>\w+)=(?P<v>\w+)").unwrap();
+
+        let m = re.capture_first_named("x=42").unwrap();
+        assert_eq!(2, m.len());
+        assert_eq!("x", m["k"]);
+        assert_eq!("42", m["v"]);
+        assert!(re.capture_first_named("no match").is_none());
+
+        let re = Regex::parse(r"(?P<k>\w+)(=(?P<v>\w+))?").unwrap();
+        let m = re.capture_first_named("flag").unwrap();
+        assert_eq!(1, m.len());
+        assert!(!m.contains_key("v"));
+
+        let re = Regex::parse(r"(\d+)").unwrap();
+        assert!(re.capture_first_named("123").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_captures_full() {
+        let re = Regex::parse(r"(?P<y>\d{4})-(?P<m>\d{2})").unwrap();
+
+        let c = re.captures_full("2022-12").unwrap();
+        assert_eq!(c.get("y").unwrap().as_str(), "2022");
+        assert_eq!(c.get("m").unwrap().as_str(), "12");
+        assert!(re.captures_full("2022-12x").is_none());
+        assert!(re.captures_full("x2022-12").is_none());
+        assert!(re.captures_full("2022").is_none());
+
+        // the leftmost-first match `a` does not span the text, but `ab` does
+        let re = Regex::parse(r"(a|ab)").unwrap();
+        assert!(!re.is_full_match("ab"));
+        assert_eq!(re.captures_full("ab").unwrap().get(1).unwrap().as_str(), "ab");
+
+        // flags in the pattern do not leak to the anchors
+        let re = Regex::parse(r"(?m)^(\w+)$").unwrap();
+        assert_eq!(re.captures_full("line").unwrap().get(1).unwrap().as_str(), "line");
+        assert!(re.captures_full("line1\nline2").is_none());
+
+        // the trailing comment of the x mode swallows the closing anchor, then falls back
+        let re = Regex::parse("(?x)(?P<y>\\d{4}) # year").unwrap();
+        assert_eq!(re.captures_full("2022").unwrap().get("y").unwrap().as_str(), "2022");
+        assert!(re.captures_full("2022-12").is_none());
+        assert!(re.captures_full("x2022").is_none());
+    }
+
     #[test]
     fn test_captures_iter_name() {
         let re = Regex::parse(r"(?P<year>\d{4})-(?P<month>\d{2})-(?P<day>\d{2})").unwrap();
@@ -262,4 +505,13 @@ mod tests_splitter {
         let re = Regex::parse(r"=_=").unwrap();
         assert_eq!(vec!["a", "b", "c"],  re.split("a=_=b=_=c").collect::<Vec<&str>>())
     }
+
+    #[test]
+    fn test_split_n() {
+        let re = Regex::parse(r"\d").unwrap();
+        assert_eq!(vec!["a", "b2c3d"], re.split_n("a1b2c3d", 2));
+        assert_eq!(vec!["a", "b", "c", "d"], re.split_n("a1b2c3d", 10));
+        assert_eq!(vec!["a1b2c3d"], re.split_n("a1b2c3d", 1));
+        assert!(re.split_n("a1b2c3d", 0).is_empty());
+    }
 }
\ No newline at end of file
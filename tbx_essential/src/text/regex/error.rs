@@ -1,3 +1,4 @@
+use std::error::Error;
 use std::fmt;
 use std::fmt::Formatter;
 use regex::Error as RegexError;
@@ -26,4 +27,23 @@ impl fmt::Debug for ParseError {
         // just delegate to [`Regex::Error`]
         self.re_err.fmt(f)
     }
-}
\ No newline at end of file
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.re_err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use crate::text::regex::Regex;
+
+    #[test]
+    fn test_error() {
+        let e: Box<dyn Error> = Box::new(Regex::parse(r"(\d").err().unwrap());
+        assert!(e.to_string().contains("unclosed group"));
+        assert!(e.source().is_some());
+    }
+}
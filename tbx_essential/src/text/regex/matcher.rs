@@ -84,6 +84,12 @@ pub struct Captures<'t> {
 
 impl<'t> Captures<'t> {
     pub fn new(c: RegexCaptures<'t>) -> Self { Self { c } }
+
+    /// Returns texts of all numbered capture groups in order, excluding the group 0 (the entire match).
+    /// The element is `None` if the group did not participate in the match.
+    pub fn all_groups(&self) -> Vec<Option<&'t str>> {
+        self.c.iter().skip(1).map(|m| m.map(|m| m.as_str())).collect()
+    }
 }
 
 impl<'t> CaptureIndexer<'t, usize> for Captures<'t> {
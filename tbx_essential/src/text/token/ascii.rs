@@ -2,6 +2,46 @@ use std::borrow::Cow;
 
 use crate::text::essential::StringEssential;
 
+/// Case conversion applied to each token by [`join_as`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// Upper case for the first char, and the rest as is. Example: `"rustLang"` -> `"RustLang"`.
+    Upper,
+    /// All lower case. Example: `"RustLang"` -> `"rustlang"`.
+    Lower,
+    /// All upper case. Example: `"RustLang"` -> `"RUSTLANG"`.
+    Capital,
+    /// Upper case for the first char, then lower case for the rest. Example: `"rustLang"` -> `"Rustlang"`.
+    FirstUpper,
+    /// Keep the token as is.
+    AsIs,
+}
+
+impl Case {
+    /// Convert the case of the token.
+    pub fn apply(&self, token: &str) -> String {
+        match self {
+            Case::Upper | Case::FirstUpper => {
+                match (token.substring(0, 1), token.substring_to_end(1)) {
+                    (Some(h), Some(r)) if *self == Case::FirstUpper => h.to_uppercase() + &r.to_lowercase(),
+                    (Some(h), Some(r)) => h.to_uppercase() + r,
+                    (Some(h), None) => h.to_uppercase(),
+                    _ => String::new(),
+                }
+            }
+            Case::Lower => token.to_lowercase(),
+            Case::Capital => token.to_uppercase(),
+            Case::AsIs => token.to_string(),
+        }
+    }
+}
+
+/// Join tokens with the separator after converting the case of each token.
+/// Example: `["foo", "bar"]` with `"_"` and [`Case::Capital`] -> `"FOO_BAR"`.
+pub fn join_as(tokens: &[&str], sep: &str, case: Case) -> String {
+    tokens.iter().map(|token| case.apply(token)).collect::<Vec<String>>().join(sep)
+}
+
 pub trait AsciiTokenizer {
     /// Split into alpha-numeric tokens.
     /// This tokenizer ignores characters except ASCII alpha-numeric.
@@ -12,6 +52,15 @@ pub trait AsciiTokenizer {
     /// `["Powered"`, `"by"`, `"Rust"`, `"Lang"`, `"version1"`, `"65"`, `"0"]`.
     fn tokenize_ascii_alpha_num(&self) -> Vec<&str>;
 
+    /// Same as [`Self::tokenize_ascii_alpha_num`] but returns tokens with the byte range
+    /// `(start_byte, end_byte, token)`, where `token == &self[start_byte..end_byte]`.
+    fn tokenize_ascii_alpha_num_with_spans(&self) -> Vec<(usize, usize, &str)>;
+
+    /// Same as [`Self::tokenize_ascii_alpha_num`] but additionally splits the boundary
+    /// between a letter run and a digit run.
+    /// For example, `"version1.65.0"` is tokenized to `["version", "1", "65", "0"]`.
+    fn tokenize_ascii_alpha_num_split_digits(&self) -> Vec<&str>;
+
     /// Split into alpha-numeric tokens, then change all cases to capital.
     /// Other behavior is same as [`Self::tokenize_ascii_alpha_num`]
     /// Example: `"Powered by RustLang"` -> `["POWERED", "BY", "RUST", "LANG"]`
@@ -62,44 +111,57 @@ fn next_alpha_num_token(s: &str) -> Option<(usize, usize, &str)> {
     }
 }
 
+/// Iterator over alpha-numeric tokens with the byte range `(start_byte, end_byte, token)`.
+struct AlphaNumSpans<'a> {
+    text: &'a str,
+
+    /// Byte offset to search the next token.
+    offset: usize,
+}
+
+impl<'a> Iterator for AlphaNumSpans<'a> {
+    type Item = (usize, usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let reminder = self.text.get(self.offset..)?;
+        let (s, _f, token) = next_alpha_num_token(reminder)?;
+        // positions of `next_alpha_num_token` are char indices, chars before the token may be non-ASCII
+        let start = self.offset + reminder.chars().take(s).map(|c| c.len_utf8()).sum::<usize>();
+        let end = start + token.len();
+        self.offset = end;
+        Some((start, end, token))
+    }
+}
+
 impl AsciiTokenizer for str {
     fn tokenize_ascii_alpha_num(&self) -> Vec<&str> {
-        let mut tokens: Vec<&str> = Vec::new();
-        let mut offset: usize = 0;
-
-        while let Some(reminder) = self.substring_to_end(offset) {
-            match next_alpha_num_token(reminder) {
-                Some((_s, f, token)) => {
-                    tokens.push(token);
-                    offset += f;
-                }
-                _ => break
-            }
-        }
+        AlphaNumSpans { text: self, offset: 0 }.map(|(_s, _f, token)| token).collect()
+    }
 
-        tokens
+    fn tokenize_ascii_alpha_num_with_spans(&self) -> Vec<(usize, usize, &str)> {
+        AlphaNumSpans { text: self, offset: 0 }.collect()
     }
 
-    fn tokenize_ascii_alpha_num_to_capital<'a>(&self) -> Vec<Cow<'a, str>> {
-        self.tokenize_ascii_alpha_num().iter().map(|token| {
-            Cow::Owned(token.to_string().to_uppercase())
+    fn tokenize_ascii_alpha_num_split_digits(&self) -> Vec<&str> {
+        // tokens are ASCII only, then the char position is the byte offset
+        self.tokenize_ascii_alpha_num().into_iter().flat_map(|token| {
+            match token.chars().position(|c| c.is_ascii_digit()) {
+                Some(p) if p > 0 => vec![&token[..p], &token[p..]],
+                _ => vec![token],
+            }
         }).collect()
     }
 
+    fn tokenize_ascii_alpha_num_to_capital<'a>(&self) -> Vec<Cow<'a, str>> {
+        self.tokenize_ascii_alpha_num().iter().map(|token| Cow::Owned(Case::Capital.apply(token))).collect()
+    }
+
     fn tokenize_ascii_alpha_num_to_first_upper<'a>(&self) -> Vec<Cow<'a, str>> {
-        self.tokenize_ascii_alpha_num().iter().map(|token| {
-            match (token.substring(0, 1), token.substring_to_end(1)) {
-                (Some(h), None) => Cow::Owned(h.to_uppercase().to_string()),
-                (Some(h), Some(r)) => Cow::Owned(h.to_uppercase() + &r.to_lowercase()),
-                _ => Cow::Owned(String::from("")),
-            }
-        }).collect()
+        self.tokenize_ascii_alpha_num().iter().map(|token| Cow::Owned(Case::FirstUpper.apply(token))).collect()
     }
 
     fn tokenize_ascii_alpha_num_to_lower<'a>(&self) -> Vec<Cow<'a, str>> {
-        self.tokenize_ascii_alpha_num().iter().map(|token| {
-            Cow::Owned(token.to_string().to_lowercase())
-        }).collect()
+        self.tokenize_ascii_alpha_num().iter().map(|token| Cow::Owned(Case::Lower.apply(token))).collect()
     }
 }
 
@@ -119,7 +181,30 @@ impl AsciiMatcher for str {
 
 #[cfg(test)]
 mod tests {
-    use crate::text::token::ascii::{AsciiMatcher, AsciiTokenizer};
+    use crate::text::token::ascii::{join_as, AsciiMatcher, AsciiTokenizer, Case};
+
+    #[test]
+    fn test_case_apply() {
+        assert_eq!("RustLang", Case::Upper.apply("rustLang"));
+        assert_eq!("rustlang", Case::Lower.apply("RustLang"));
+        assert_eq!("RUSTLANG", Case::Capital.apply("RustLang"));
+        assert_eq!("Rustlang", Case::FirstUpper.apply("rustLang"));
+        assert_eq!("rustLang", Case::AsIs.apply("rustLang"));
+        assert_eq!("", Case::FirstUpper.apply(""));
+    }
+
+    #[test]
+    fn test_join_as() {
+        assert_eq!("FOO_BAR", join_as(&["foo", "bar"], "_", Case::Capital));
+        assert_eq!("foo-bar", join_as(&["Foo", "BAR"], "-", Case::Lower));
+        assert_eq!("Foo Bar", join_as(&["foo", "bAR"], " ", Case::FirstUpper));
+        assert_eq!("FooBAR", join_as(&["foo", "bAR"], "", Case::Upper));
+        assert_eq!("foo.bAR", join_as(&["foo", "bAR"], ".", Case::AsIs));
+        assert_eq!("X", join_as(&["x"], "_", Case::FirstUpper));
+        assert_eq!("", join_as(&[], "_", Case::Capital));
+        assert_eq!("Powered_By_Rust_Lang",
+                   join_as(&"  Powered by RustLang".tokenize_ascii_alpha_num(), "_", Case::FirstUpper));
+    }
 
     #[test]
     fn test_tokenize_alpha_num() {
@@ -145,6 +230,34 @@ mod tests {
                    "  789 １   １２　１２３".tokenize_ascii_alpha_num());
     }
 
+    #[test]
+    fn test_tokenize_ascii_alpha_num_with_spans() {
+        let input = "  Powered by";
+        let spans = input.tokenize_ascii_alpha_num_with_spans();
+        assert_eq!(vec![(2, 9, "Powered"), (10, 12, "by")], spans);
+
+        let input = "ラーメン RAMEN123 123Ｘ Udon";
+        let spans = input.tokenize_ascii_alpha_num_with_spans();
+        assert_eq!(vec!["RAMEN123", "123", "Udon"], spans.iter().map(|(_, _, t)| *t).collect::<Vec<&str>>());
+        assert_eq!(13, spans[0].0);
+        for (start, end, token) in spans {
+            assert_eq!(token, &input[start..end]);
+        }
+
+        assert!("".tokenize_ascii_alpha_num_with_spans().is_empty());
+    }
+
+    #[test]
+    fn test_tokenize_ascii_alpha_num_split_digits() {
+        assert_eq!(vec!["version", "1"], "version1".tokenize_ascii_alpha_num_split_digits());
+        assert_eq!(vec!["RAMEN", "123"], "RAMEN123".tokenize_ascii_alpha_num_split_digits());
+        assert_eq!(vec!["123", "abc"], "123abc".tokenize_ascii_alpha_num_split_digits());
+        assert_eq!(vec!["Powered", "by", "Rust", "Lang", "version", "1", "65", "0"],
+                   "  Powered by RustLang version1.65.0".tokenize_ascii_alpha_num_split_digits());
+        assert_eq!(vec!["Ver", "1", "b"], "Ver1b".tokenize_ascii_alpha_num_split_digits());
+        assert!("".tokenize_ascii_alpha_num_split_digits().is_empty());
+    }
+
     #[test]
     fn test_tokenize_ascii_alpha_num_to_capital() {
         assert_eq!(vec!["POWERED", "BY", "RUST", "LANG", "VERSION1", "65", "0"],
@@ -2,6 +2,10 @@ pub mod error;
 pub mod v4;
 
 use std::borrow::Cow;
+use std::fmt;
+use std::fmt::Formatter;
+use crate::text::base32;
+use crate::text::base64;
 use crate::text::hex;
 use crate::text::hex::Hex;
 use crate::text::regex::{Matcher, Regex};
@@ -77,6 +81,18 @@ pub trait Layout {
     /// Returns URN of the UUID like `urn:uuid:123e4567-e89b-12d3-a456-426655440000`.
     fn urn<'a>(&self) -> Cow<'a, str>;
 
+    /// Returns abbreviated UUID for display like `123e4567…40000`.
+    /// The first `head` and the last `tail` hex characters of the hyphen-free form
+    /// are joined by `…`. Returns whole hyphen-free form if `head + tail` covers entire UUID.
+    fn abbreviated(&self, head: usize, tail: usize) -> String;
+
+    /// Returns compact form of the UUID in Crockford's Base32 (26 characters) like `28Z4ASZ8KC9D792P89K5AH0000`.
+    fn to_base32_crockford(&self) -> String;
+
+    /// Returns compact form of the UUID in URL safe Base64 without padding (22 characters)
+    /// like `Ej5FZ-ibEtOkVkJmVUQAAA`.
+    fn to_base64url(&self) -> String;
+
     /// Variant of the UUID.
     /// The variant field determines the layout of the UUID.
     fn variant(&self) -> Variant;
@@ -84,6 +100,15 @@ pub trait Layout {
     /// Version of the UUID.
     fn version(&self) -> Version;
 
+    /// Returns the raw 4-bit version field of the UUID (`0` to `15`).
+    /// Unlike [`Self::version`], values without a defined version (e.g. `9` to `15`) are preserved.
+    fn version_number(&self) -> u8;
+
+    /// Returns Unix timestamp in milliseconds embedded in the time-based UUID.
+    /// Returns `None` if the UUID is not version 1, 6 nor 7.
+    /// Version 1/6 timestamp has 100-nanosecond resolution, which is truncated to milliseconds.
+    fn timestamp_millis(&self) -> Option<i64>;
+
     /// Returns true if the UUID is Nil UUID (all zero).
     /// The nil UUID is special form of UUID that is
     /// specified to have all 128 bits set to zero.
@@ -98,7 +123,7 @@ pub trait Layout {
 }
 
 /// UUID data.
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 pub struct UUID {
     data: [u8; 16],
 }
@@ -108,6 +133,9 @@ const UUID_REGEX_URN: &str = r"urn:uuid:(?P<u0>[0-9a-fA-F]{8})-(?P<u1>[0-9a-fA-F
 const UUID_REGEX_MICROSOFT: &str = r"\{(?P<u0>[0-9a-fA-F]{8})-(?P<u1>[0-9a-fA-F]{4})-(?P<u2>[0-9a-fA-F]{4})-(?P<u3>[0-9a-fA-F]{4})-(?P<u4>[0-9a-fA-F]{12})\}";
 const UUID_REGEX_NOHYPHEN: &str = r"(?P<u0>[0-9a-fA-F]{8})(?P<u1>[0-9a-fA-F]{4})(?P<u2>[0-9a-fA-F]{4})(?P<u3>[0-9a-fA-F]{4})(?P<u4>[0-9a-fA-F]{12})";
 
+/// Number of 100-nanosecond intervals between the UUID epoch (1582-10-15) and the Unix epoch (1970-01-01).
+const GREGORIAN_TO_UNIX_INTERVALS: i64 = 0x01B2_1DD2_1381_4000;
+
 /// Namespace of fully-qualified domain name (for Version 3/5 UUID).
 pub const NAMESPACE_DNS: &str = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
 
@@ -123,6 +151,11 @@ pub const NAMESPACE_X500: &str = "6ba7b814-9dad-11d1-80b4-00c04fd430c8";
 impl UUID {
     pub fn new(data: [u8; 16]) -> Self { Self { data } }
 
+    /// Create new UUID version 4 (randomly generated UUID). See [`v4::new`].
+    pub fn new_v4() -> Self {
+        v4::new()
+    }
+
     pub fn parse(uuid: &str) -> Result<Self, ParseError> {
         let patterns = vec![UUID_REGEX_RFC4122, UUID_REGEX_NOHYPHEN, UUID_REGEX_URN, UUID_REGEX_MICROSOFT];
         for pattern in patterns {
@@ -142,6 +175,73 @@ impl UUID {
         Err(InvalidPattern)
     }
 
+    /// Parse URL safe Base64 form of the UUID (see [`Layout::to_base64url`]).
+    pub fn parse_base64url(s: &str) -> Result<Self, ParseError> {
+        if s.len() != 22 {
+            return Err(InvalidPattern);
+        }
+        match base64::decode(s).map(<[u8; 16]>::try_from) {
+            Ok(Ok(data)) => Ok(Self { data }),
+            _ => Err(InvalidPattern),
+        }
+    }
+
+    /// Compare UUIDs ignoring version and variant bits.
+    /// Masked bits are the version nibble (the most significant 4 bits of the octet 6) and
+    /// the RFC 4122 variant field (the most significant 2 bits of the octet 8).
+    /// Other 122 bits are compared.
+    pub fn eq_ignoring_version_variant(&self, other: &UUID) -> bool {
+        let mask = |data: &[u8; 16]| -> [u8; 16] {
+            let mut d = *data;
+            d[6] &= 0x0f;
+            d[8] &= 0x3f;
+            d
+        };
+        mask(&self.data) == mask(&other.data)
+    }
+
+    /// Returns true if `s` represents the same UUID, in any format supported by [`UUID::parse`]
+    /// (e.g. `{C232AB00-...}`, `urn:uuid:c232ab00-...` or without hyphens), regardless of the case.
+    /// Returns false if `s` is not a UUID.
+    pub fn eq_str(&self, s: &str) -> bool {
+        match Self::parse(s) {
+            Ok(u) => u.data == self.data,
+            Err(_) => false,
+        }
+    }
+
+    /// Returns the UUID with the version nibble (the most significant 4 bits of the octet 6) set to `v`.
+    /// Other bits are unchanged. [`Version::Undefined`] sets the nibble to `0`.
+    pub fn with_version(self, v: Version) -> UUID {
+        let nibble: u8 = match v {
+            Version::Version1 => 1,
+            Version::Version2 => 2,
+            Version::Version3 => 3,
+            Version::Version4 => 4,
+            Version::Version5 => 5,
+            Version::Version6Draft => 6,
+            Version::Version7Draft => 7,
+            Version::Version8Draft => 8,
+            Version::Undefined => 0,
+        };
+        let mut data = self.data;
+        data[6] = (data[6] & 0x0f) | nibble << 4;
+        UUID { data }
+    }
+
+    /// Returns the UUID with the variant field (the most significant 1 to 3 bits of the octet 8) set to `variant`.
+    /// Other bits are unchanged.
+    pub fn with_variant(self, variant: Variant) -> UUID {
+        let mut data = self.data;
+        data[8] = match variant {
+            Variant::NCS => data[8] & 0x7f,
+            Variant::RFC4122 => (data[8] & 0x3f) | 0x80,
+            Variant::Microsoft => (data[8] & 0x1f) | 0xc0,
+            Variant::Reserved => (data[8] & 0x1f) | 0xe0,
+        };
+        UUID { data }
+    }
+
     pub fn nil_uuid() -> Self {
         Self {
             data: [0; 16]
@@ -174,6 +274,20 @@ impl UUID {
     }
 }
 
+/// Big endian bytes (up to 8 bytes) to u64.
+fn be_u64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |acc, b| acc << 8 | *b as u64)
+}
+
+/// Sort UUIDs by the embedded timestamp (see [`Layout::timestamp_millis`]).
+/// UUIDs without timestamp are placed at the end, in the original order.
+pub fn sort_by_timestamp(uuids: &mut [UUID]) {
+    uuids.sort_by_key(|u| {
+        let ts = u.timestamp_millis();
+        (ts.is_none(), ts)
+    });
+}
+
 impl Layout for UUID {
     fn uuid_lower<'a>(&self) -> Cow<'a, str> {
         self.data[0..4].to_hex_lower() + "-" +
@@ -199,6 +313,24 @@ impl Layout for UUID {
         Cow::from("urn:uuid:") + self.uuid_lower()
     }
 
+    fn abbreviated(&self, head: usize, tail: usize) -> String {
+        let hex = self.data.to_hex_lower();
+        let len = hex.len();
+        if len <= head.saturating_add(tail) {
+            hex.to_string()
+        } else {
+            format!("{}…{}", &hex[..head], &hex[len - tail..])
+        }
+    }
+
+    fn to_base32_crockford(&self) -> String {
+        base32::encode_crockford(&self.data)
+    }
+
+    fn to_base64url(&self) -> String {
+        base64::encode_url_safe(&self.data)
+    }
+
     fn variant(&self) -> Variant {
         let x = self.data[8] >> 4;
         if x & 0b1000 == 0 {
@@ -213,7 +345,7 @@ impl Layout for UUID {
     }
 
     fn version(&self) -> Version {
-        match self.data[6] >> 4 {
+        match self.version_number() {
             1 => Version::Version1,
             2 => Version::Version2,
             3 => Version::Version3,
@@ -226,6 +358,27 @@ impl Layout for UUID {
         }
     }
 
+    fn version_number(&self) -> u8 {
+        self.data[6] >> 4
+    }
+
+    fn timestamp_millis(&self) -> Option<i64> {
+        let d = &self.data;
+        let gregorian_to_unix_millis = |intervals: u64| -> i64 {
+            (intervals as i64 - GREGORIAN_TO_UNIX_INTERVALS).div_euclid(10_000)
+        };
+        match self.version() {
+            Version::Version1 => Some(gregorian_to_unix_millis(
+                (be_u64(&d[6..8]) & 0x0fff) << 48 | be_u64(&d[4..6]) << 32 | be_u64(&d[0..4])
+            )),
+            Version::Version6Draft => Some(gregorian_to_unix_millis(
+                be_u64(&d[0..4]) << 28 | be_u64(&d[4..6]) << 12 | (be_u64(&d[6..8]) & 0x0fff)
+            )),
+            Version::Version7Draft => Some(be_u64(&d[0..6]) as i64),
+            _ => None,
+        }
+    }
+
     fn is_nil(&self) -> bool {
         self.data.iter().all(|x| *x == 0)
     }
@@ -235,12 +388,22 @@ impl Layout for UUID {
     }
 }
 
+/// Debug format with the canonical form, version and variant like
+/// `UUID(123e4567-e89b-12d3-a456-426655440000, version: Version1, variant: RFC4122)`.
+impl fmt::Debug for UUID {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "UUID({}, version: {:?}, variant: {:?})", self.uuid_lower(), self.version(), self.variant())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::text::uuid::UUID;
+    use crate::text::base32::decode_crockford;
+    use crate::text::uuid::{sort_by_timestamp, UUID};
     use crate::text::uuid::Layout;
+    use crate::text::uuid::Variant;
     use crate::text::uuid::Variant::RFC4122;
-    use crate::text::uuid::Version::{Version1, Version3, Version4, Version5, Version6Draft, Version7Draft, Version8Draft};
+    use crate::text::uuid::Version::{Undefined, Version1, Version3, Version4, Version5, Version6Draft, Version7Draft, Version8Draft};
 
     #[test]
     fn test_nil() {
@@ -263,6 +426,7 @@ mod tests {
     fn test_parse() {
         let u0 = UUID::parse("00000000-0000-0000-0000-000000000000").unwrap();
         assert!(u0.is_nil());
+        assert_eq!("invalid UUID pattern", UUID::parse("not-a-uuid").unwrap_err().to_string());
 
         let v1 = UUID::parse("{C232AB00-9414-11EC-B3C8-9E6BDECED846}").unwrap();
         assert_eq!(v1.version(), Version1);
@@ -300,6 +464,172 @@ mod tests {
         assert_eq!("320C3D4D-CC00-875B-8EC9-32D5F69181C0", v8.uuid_upper());
     }
 
+    #[test]
+    fn test_abbreviated() {
+        let u = UUID::parse("123e4567-e89b-12d3-a456-426655440000").unwrap();
+        assert_eq!("123e4567…0000", u.abbreviated(8, 4));
+        assert_eq!("123e…40000", u.abbreviated(4, 5));
+        assert_eq!("…", u.abbreviated(0, 0));
+        assert_eq!("123e4567e89b12d3a456426655440000", u.abbreviated(16, 16));
+        assert_eq!("123e4567e89b12d3a456426655440000", u.abbreviated(20, 20));
+        assert_eq!("123e4567e89b12d3a456426655440000", u.abbreviated(usize::MAX, 1));
+        assert_eq!("123e4567e89b12d3a456426655440000", u.abbreviated(usize::MAX, usize::MAX));
+    }
+
+    #[test]
+    fn test_to_base32_crockford() {
+        let u = UUID::parse("123e4567-e89b-12d3-a456-426655440000").unwrap();
+        assert_eq!("28Z4ASZ8KC9D792P89K5AH0000", u.to_base32_crockford());
+
+        let n = UUID::nil_uuid();
+        let nb = n.to_base32_crockford();
+        assert_eq!("00000000000000000000000000", nb);
+        assert_eq!(n.data.to_vec(), decode_crockford(&nb).unwrap());
+
+        let v4 = UUID::parse("f07535d3-228a-4ac3-a900-57081609572e").unwrap();
+        let v4b = v4.to_base32_crockford();
+        assert_eq!(26, v4b.len());
+        assert_eq!(v4.data.to_vec(), decode_crockford(&v4b.to_ascii_lowercase()).unwrap());
+    }
+
+    #[test]
+    fn test_to_base64url() {
+        let u = UUID::parse("123e4567-e89b-12d3-a456-426655440000").unwrap();
+        assert_eq!("Ej5FZ-ibEtOkVkJmVUQAAA", u.to_base64url());
+
+        let v4 = UUID::parse("fbf7ffd3-228a-4ac3-a900-57081609572e").unwrap();
+        let encoded = v4.to_base64url();
+        assert_eq!(22, encoded.len());
+        assert!(!encoded.contains(['+', '/', '=']));
+        assert_eq!(v4, UUID::parse_base64url(&encoded).unwrap());
+
+        assert!(UUID::parse_base64url("Ej5FZ-ibEtOkVkJmVUQAAA==").is_err());
+        assert!(UUID::parse_base64url("Ej5FZ-ibEtOkVkJmVUQAA").is_err());
+        assert!(UUID::parse_base64url("Ej5FZ-ibEtOkVkJmVUQAA*").is_err());
+    }
+
+    #[test]
+    fn test_eq_ignoring_version_variant() {
+        let v1 = UUID::parse("C232AB00-9414-11EC-B3C8-9E6BDECED846").unwrap();
+        let v4 = UUID::parse("C232AB00-9414-41EC-B3C8-9E6BDECED846").unwrap();
+        assert_ne!(v1, v4);
+        assert!(v1.eq_ignoring_version_variant(&v4));
+
+        let variant_ncs = UUID::parse("C232AB00-9414-41EC-33C8-9E6BDECED846").unwrap();
+        assert!(v4.eq_ignoring_version_variant(&variant_ncs));
+
+        let clock_seq = UUID::parse("C232AB00-9414-41EC-B3C9-9E6BDECED846").unwrap();
+        assert!(!v4.eq_ignoring_version_variant(&clock_seq));
+        let low_version_bits = UUID::parse("C232AB00-9414-40EC-B3C8-9E6BDECED846").unwrap();
+        assert!(!v4.eq_ignoring_version_variant(&low_version_bits));
+    }
+
+    #[test]
+    fn test_timestamp_millis() {
+        // 2022-02-22T19:22:22Z, examples of the draft-peabody-dispatch-new-uuid-format-04
+        let v1 = UUID::parse("C232AB00-9414-11EC-B3C8-9E6BDECED846").unwrap();
+        let v6 = UUID::parse("1EC9414C-232A-6B00-B3C8-9E6BDECED846").unwrap();
+        let v7 = UUID::parse("017F22E2-79B0-7CC3-98C4-DC0C0C07398F").unwrap();
+        assert_eq!(Some(1645557742000), v1.timestamp_millis());
+        assert_eq!(Some(1645557742000), v6.timestamp_millis());
+        assert_eq!(Some(1645557742000), v7.timestamp_millis());
+
+        let v4 = UUID::parse("f07535d3-228a-4ac3-a900-57081609572e").unwrap();
+        assert_eq!(None, v4.timestamp_millis());
+        assert_eq!(None, UUID::nil_uuid().timestamp_millis());
+    }
+
+    #[test]
+    fn test_sort_by_timestamp() {
+        let v4a = UUID::parse("f07535d3-228a-4ac3-a900-57081609572e").unwrap();
+        let v4b = UUID::parse("375dde34-fc9b-4822-8191-6b2199358695").unwrap();
+        let v7a = UUID::parse("017F22E2-79B0-7CC3-98C4-DC0C0C07398F").unwrap();
+        let v7b = UUID::parse("017F22E2-79B1-7CC3-98C4-DC0C0C07398F").unwrap();
+        let v7c = UUID::parse("017F22E3-0000-7CC3-98C4-DC0C0C07398F").unwrap();
+        let v1 = UUID::parse("C232AB00-9414-11EC-B3C8-9E6BDECED846").unwrap();
+
+        let mut uuids = vec![v7c, v4a, v7b, v4b, v7a];
+        sort_by_timestamp(&mut uuids);
+        assert_eq!(vec![v7a, v7b, v7c, v4a, v4b], uuids);
+
+        let mut mixed = vec![v4a, v7b, v1];
+        sort_by_timestamp(&mut mixed);
+        assert_eq!(vec![v1, v7b, v4a], mixed);
+    }
+
+    #[test]
+    fn test_new_v4() {
+        let u = UUID::new_v4();
+        assert_eq!(u.version(), Version4);
+        assert_eq!(u.variant(), RFC4122);
+        assert_ne!(u, UUID::new_v4());
+    }
+
+    #[test]
+    fn test_eq_str() {
+        let u = UUID::parse("c232ab00-9414-11ec-b3c8-9e6bdeced846").unwrap();
+        assert!(u.eq_str("{C232AB00-9414-11EC-B3C8-9E6BDECED846}"));
+        assert!(u.eq_str("urn:uuid:c232ab00-9414-11ec-b3c8-9e6bdeced846"));
+        assert!(u.eq_str("C232AB00941411ECB3C89E6BDECED846"));
+        assert!(u.eq_str("c232ab00-9414-11EC-b3c8-9E6BDECED846"));
+
+        assert!(!u.eq_str("c232ab00-9414-11ec-b3c8-9e6bdeced847"));
+        assert!(!u.eq_str("not a uuid"));
+        assert!(!u.eq_str(""));
+    }
+
+    #[test]
+    fn test_with_version() {
+        let v4 = UUID::parse("f07535d3-228a-4ac3-a900-57081609572e").unwrap();
+        let v7 = v4.with_version(Version7Draft);
+        assert_eq!(v7.version(), Version7Draft);
+        assert_eq!("f07535d3-228a-7ac3-a900-57081609572e", v7.uuid_lower());
+        assert_eq!(v7.variant(), RFC4122);
+        assert_eq!(v4, v7.with_version(Version4));
+
+        let max = UUID::max_uuid().with_version(Version1);
+        assert_eq!("ffffffff-ffff-1fff-ffff-ffffffffffff", max.uuid_lower());
+    }
+
+    #[test]
+    fn test_debug() {
+        let u = UUID::parse("F07535D3-228A-4AC3-A900-57081609572E").unwrap();
+        assert_eq!("UUID(f07535d3-228a-4ac3-a900-57081609572e, version: Version4, variant: RFC4122)", format!("{:?}", u));
+        assert!(format!("{:?}", UUID::nil_uuid()).contains("00000000-0000-0000-0000-000000000000, version: Undefined"));
+    }
+
+    #[test]
+    fn test_version_number() {
+        let v7 = UUID::parse("017F22E2-79B0-7CC3-98C4-DC0C0C07398F").unwrap();
+        assert_eq!(7, v7.version_number());
+        assert_eq!(4, UUID::parse("f07535d3-228a-4ac3-a900-57081609572e").unwrap().version_number());
+        assert_eq!(0, UUID::nil_uuid().version_number());
+
+        let mut data = [0u8; 16];
+        data[6] = 0xf0;
+        let u = UUID::new(data);
+        assert_eq!(15, u.version_number());
+        assert_eq!(Undefined, u.version());
+    }
+
+    #[test]
+    fn test_with_variant() {
+        let n = UUID::nil_uuid().with_variant(RFC4122);
+        assert_eq!(n.variant(), RFC4122);
+        assert_eq!("00000000-0000-0000-8000-000000000000", n.uuid_lower());
+
+        let m = UUID::max_uuid().with_variant(RFC4122);
+        assert_eq!(m.variant(), RFC4122);
+        assert_eq!("ffffffff-ffff-ffff-bfff-ffffffffffff", m.uuid_lower());
+
+        let v4 = UUID::parse("f07535d3-228a-4ac3-a900-57081609572e").unwrap();
+        assert_eq!(v4.with_variant(Variant::NCS).variant(), Variant::NCS);
+        assert_eq!(v4.with_variant(Variant::Microsoft).variant(), Variant::Microsoft);
+        assert_eq!(v4.with_variant(Variant::Reserved).variant(), Variant::Reserved);
+        assert_eq!(v4, v4.with_variant(Variant::Reserved).with_variant(RFC4122));
+        assert_eq!(v4.with_variant(Variant::NCS).version(), Version4);
+    }
+
     #[test]
     fn test_versions() {}
 }
\ No newline at end of file
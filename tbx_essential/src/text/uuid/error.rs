@@ -1,7 +1,51 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+
 /// Parser error
 #[derive(Debug)]
 pub enum ParseError {
+    /// The string does not match any of supported UUID formats.
     InvalidPattern,
+
+    /// Failed to prepare the regular expression for the UUID formats.
     SystemError
 }
 
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidPattern => write!(f, "invalid UUID pattern"),
+            ParseError::SystemError => write!(f, "regex system error"),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use crate::text::uuid::error::ParseError;
+    use crate::text::uuid::{Layout, UUID};
+
+    fn parse_lower(uuid: &str) -> Result<String, Box<dyn Error>> {
+        Ok(UUID::parse(uuid)?.uuid_lower().to_string())
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("invalid UUID pattern", ParseError::InvalidPattern.to_string());
+        assert_eq!("regex system error", ParseError::SystemError.to_string());
+        assert_eq!("regex system error", format!("{}", ParseError::SystemError));
+    }
+
+    #[test]
+    fn test_error() {
+        let e: Box<dyn Error> = Box::new(UUID::parse("not-a-uuid").err().unwrap());
+        assert_eq!("invalid UUID pattern", e.to_string());
+
+        assert_eq!("123e4567-e89b-12d3-a456-426655440000", parse_lower("123E4567-E89B-12D3-A456-426655440000").unwrap());
+        assert_eq!("invalid UUID pattern", parse_lower("not-a-uuid").err().unwrap().to_string());
+    }
+}
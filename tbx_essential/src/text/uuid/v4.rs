@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 use crate::number::random::{Generator, Random};
-use crate::text::uuid::{Layout, UUID};
+use crate::text::uuid::{Layout, Variant, Version, UUID};
 
 /// Create new UUID version 4 (randomly generated UUID) with given random generator.
 pub fn new_with_rand(r: &mut Random) -> UUID {
@@ -9,10 +9,10 @@ pub fn new_with_rand(r: &mut Random) -> UUID {
     for i in 0..16 {
         data[i] = r.next_u8();
     }
-    data[6] = (data[6] & 0x0f) | 0x40; // Version 4
-    data[8] = (data[8] & 0x3f) | 0x80; // RFC 4122 Variant
 
     UUID::new(data)
+        .with_version(Version::Version4)
+        .with_variant(Variant::RFC4122)
 }
 
 /// Create new UUID version 4 (randomly generated UUID) by default random generator.
@@ -20,6 +20,11 @@ pub fn new() -> UUID {
     new_with_rand(&mut Random::new_thread_local())
 }
 
+/// Create `count` UUIDs version 4 by sharing one random generator.
+pub fn new_batch(count: usize) -> Vec<UUID> {
+    let mut r = Random::new_thread_local();
+    (0..count).map(|_| new_with_rand(&mut r)).collect()
+}
 
 /// Create new UUID version 4 string.
 pub fn new_str<'a>() -> Cow<'a, str> {
@@ -30,7 +35,7 @@ pub fn new_str<'a>() -> Cow<'a, str> {
 mod tests {
     use std::borrow::Borrow;
     use crate::text::uuid::{Layout, UUID, Variant, Version};
-    use crate::text::uuid::v4::{new, new_str};
+    use crate::text::uuid::v4::{new, new_batch, new_str};
 
     #[test]
     fn test_v4() {
@@ -46,8 +51,21 @@ mod tests {
         let v4s = new_str();
         let v4p = UUID::parse(v4s.borrow()).unwrap();
 
-        assert_eq!(v4s.borrow(), v4p.uuid_lower());
+        assert_eq!(v4s, v4p.uuid_lower());
         assert_eq!(v4p.variant(), Variant::RFC4122);
         assert_eq!(v4p.version(), Version::Version4);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_new_batch() {
+        let batch = new_batch(100);
+        assert_eq!(100, batch.len());
+        for (i, u) in batch.iter().enumerate() {
+            assert_eq!(u.variant(), Variant::RFC4122);
+            assert_eq!(u.version(), Version::Version4);
+            assert!(batch[i + 1..].iter().all(|o| o != u));
+        }
+
+        assert!(new_batch(0).is_empty());
+    }
+}
@@ -3,12 +3,18 @@ use std::fmt;
 use std::fmt::Formatter;
 
 use build::Build;
+use compact::{CompactVersion, SuffixPool};
 use prerelease::PreRelease;
 
-use crate::text::essential::StringEssential;
+use crate::text::essential::{trim_mode, StringEssential, TrimMode};
+use crate::text::regex::{Matcher, Regex};
+use crate::text::regex::matcher::CaptureIndexer;
+use crate::time::duration::Duration;
+use crate::time::monotonic::Instant;
 use crate::text::version::semantic::error::{ParseError, ParseErrorReason, ParseInvalidPart};
 use crate::text::version::semantic::error::ParseErrorReason::InvalidPattern;
 
+pub mod compact;
 mod build;
 mod prerelease;
 mod parse;
@@ -17,7 +23,7 @@ mod error;
 
 /// Structure for Semantic versioning elements.
 /// see: <https://semver.org> for more detail about semantic versioning.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Version<'a> {
     pub major: u64,
     pub minor: u64,
@@ -51,6 +57,76 @@ impl<'a> Version<'a> {
     }
 }
 
+// Properties
+impl<'a> Version<'a> {
+    /// Returns true if the version has `<pre-release>` like `1.0.0-rc.1`.
+    pub fn is_prerelease(&self) -> bool {
+        self.pre_release.is_some()
+    }
+
+    /// Returns identifiers of `<pre-release>` like `["rc", "1"]` of `1.0.0-rc.1`.
+    pub fn pre_release_identifiers(&self) -> Option<&[&'a str]> {
+        self.pre_release.as_ref().map(|p| p.identifiers())
+    }
+
+    /// Returns identifiers of `<build>` like `["exp", "sha"]` of `1.0.0+exp.sha`.
+    pub fn build_identifiers(&self) -> Option<&[&'a str]> {
+        self.build.as_ref().map(|b| b.identifiers())
+    }
+
+    /// Returns the release line `(<major>, <minor>)` like `(1, 2)` of `1.2.3-rc.1`.
+    pub fn release_line(&self) -> (u64, u64) {
+        (self.major, self.minor)
+    }
+}
+
+// Conversions
+impl<'a> Version<'a> {
+    /// Convert into memory efficient representation. See [`CompactVersion`] for the layout.
+    pub fn into_compact(self) -> CompactVersion {
+        CompactVersion::from(self)
+    }
+
+    /// Convert into memory efficient representation, sharing the `<pre-release>` and `<build>`
+    /// with other versions converted with the same pool.
+    pub fn into_compact_in(self, pool: &mut SuffixPool) -> CompactVersion {
+        CompactVersion::from_version_in(self, pool)
+    }
+
+    /// Returns JSON object like `{"major":1,"minor":2,"patch":3,"pre_release":"rc.1","build":null}`.
+    /// Absent `<pre-release>` and `<build>` are `null`.
+    pub fn to_json(&self) -> String {
+        let opt = |x: Option<String>| x.map_or("null".to_string(), |s| json_string(&s));
+        format!(
+            "{{\"major\":{},\"minor\":{},\"patch\":{},\"pre_release\":{},\"build\":{}}}",
+            self.major,
+            self.minor,
+            self.patch,
+            opt(self.pre_release.as_ref().map(|p| p.to_string())),
+            opt(self.build.as_ref().map(|b| b.to_string())),
+        )
+    }
+}
+
+/// Returns JSON string literal of `s`, with quotes.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
 // Parsers
 impl<'a> Version<'a> {
     /// Parses the string and returns the version.
@@ -88,6 +164,44 @@ impl<'a> Version<'a> {
         }
     }
 
+    /// Parses the string strictly by the semantic versioning specification.
+    /// Same as `Version::parse(ver, true)`.
+    pub fn parse_strict(ver: &str) -> Result<Version<'_>, ParseError<'_>> {
+        Self::parse(ver, true)
+    }
+
+    /// Parses the string in the relaxed mode. Same as `Version::parse(ver, false)`.
+    /// The relaxed mode accepts leading zeros in numeric identifiers like `01.0.0` or `1.0.0-rc.01`,
+    /// and identifiers that consist of identifier characters in any order like `1.0.0-12-34`.
+    pub fn parse_lenient(ver: &str) -> Result<Version<'_>, ParseError<'_>> {
+        Self::parse(ver, false)
+    }
+
+    /// Parses the string after trimming leading and trailing ASCII whitespaces like ` 1.2.3\n`.
+    /// Useful for the versions read from files. Whitespaces inside the version are still rejected.
+    pub fn parse_trimmed(ver: &str, strict: bool) -> Result<Version<'_>, ParseError<'_>> {
+        Self::parse(ver.trim_matches(|c: char| c.is_ascii_whitespace()), strict)
+    }
+
+    /// Parses the list of versions separated by commas and/or whitespaces like `1.0.0, 1.1.0 2.0.0`.
+    /// Empty entries are skipped. Entries are parsed in lenient mode, and the first error is returned
+    /// with the index of the failed entry (see [`ParseError::entry`]).
+    /// See also [`parse_version_list`] for the comma only separated list.
+    pub fn parse_list(s: &str) -> Result<Vec<Version<'_>>, ParseError<'_>> {
+        Self::parse_separated(s, |c| c == ',' || c.is_whitespace(), TrimMode::None)
+    }
+
+    /// Parses the list of versions separated by characters that match `is_separator`.
+    /// Each entry is trimmed by the `mode`, and empty entries are skipped.
+    fn parse_separated(s: &str, is_separator: impl Fn(char) -> bool, mode: TrimMode) -> Result<Vec<Version<'_>>, ParseError<'_>> {
+        s.split(is_separator)
+            .map(|v| trim_mode(v, mode))
+            .filter(|v| !v.is_empty())
+            .enumerate()
+            .map(|(i, v)| Self::parse(v, false).map_err(|e| e.with_entry(i)))
+            .collect()
+    }
+
     /// Parses the string and returns the version.
     /// If an error occurs, return the specified version.
     pub fn parse_or(ver: &'a str, major: u64, minor: u64, patch: u64) -> Version<'a> {
@@ -170,6 +284,24 @@ impl<'a> Version<'a> {
     }
 }
 
+/// Debug format with the canonical string like `Version("1.2.3-rc.1+build")`.
+/// The alternate format (`{:#?}`) shows each field.
+impl<'a> fmt::Debug for Version<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Version")
+                .field("major", &self.major)
+                .field("minor", &self.minor)
+                .field("patch", &self.patch)
+                .field("pre_release", &self.pre_release)
+                .field("build", &self.build)
+                .finish()
+        } else {
+            write!(f, "Version(\"{}\")", self)
+        }
+    }
+}
+
 impl<'a> fmt::Display for Version<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match (&self.pre_release, &self.build) {
@@ -232,6 +364,258 @@ impl<'a> PartialOrd<Self> for Version<'a> {
     }
 }
 
+// Comparisons
+impl<'a> Version<'a> {
+    /// Restrict the version into the inclusive range `min..=max` by precedence.
+    /// Returns a copy of `min` if below, a copy of `max` if above, otherwise a copy of this version.
+    ///
+    /// Panics if `min` is greater than `max`, as same as [`Ord::clamp`].
+    pub fn clamp(&self, min: &Version<'a>, max: &Version<'a>) -> Version<'a> {
+        assert!(min <= max, "min {} is greater than max {}", min, max);
+        if self < min {
+            min.clone()
+        } else if max < self {
+            max.clone()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Returns true if `other` is compatible with this version by the caret (`^`) requirement of Cargo.
+    /// This version is the baseline, then `other` must have the same or higher precedence and the same `<major>`.
+    ///
+    /// For `0.x` versions, a minor version change is considered breaking,
+    /// then `other` must have the same `<major>` and `<minor>` (e.g. `0.2.0` is compatible with `0.2.9` but not with `0.3.0`).
+    /// For `0.0.x` versions, any change is considered breaking, then `other` must have the same `<version core>`
+    /// (e.g. `0.0.3` is not compatible with `0.0.4`).
+    ///
+    /// A pre-release `other` is compatible only if this version is a pre-release of the same `<version core>`
+    /// (e.g. `1.2.0-rc.1` is compatible with `1.2.0-rc.2`, but `1.2.0` is not compatible with `1.3.0-beta`).
+    pub fn is_compatible_with(&self, other: &Version) -> bool {
+        let same_core = self.major == other.major && self.minor == other.minor && self.patch == other.patch;
+        let same_line = match (self.major, self.minor) {
+            (0, 0) => same_core,
+            (0, _) => other.major == 0 && self.minor == other.minor,
+            _ => self.major == other.major,
+        };
+        let pre_release_allowed = !other.is_prerelease() || (self.is_prerelease() && same_core);
+        same_line && pre_release_allowed && matches!(self.partial_cmp(other), Some(Ordering::Less | Ordering::Equal))
+    }
+
+    /// Returns true if `other` is in the same release line (same `<major>` and `<minor>`) as this version.
+    /// `<patch>`, `<pre-release>` and `<build>` are ignored.
+    pub fn same_minor_line(&self, other: &Version) -> bool {
+        self.release_line() == other.release_line()
+    }
+}
+
+/// Parse comma separated list of versions like `1.0.0, 1.1.0, 2.0.0-beta`.
+/// Each entry is trimmed by the `mode`, and empty entries are skipped.
+/// Entries are parsed in lenient mode, and the first error is returned with the index of the failed entry
+/// (see [`ParseError::entry`]). See also [`Version::parse_list`] for the list separated by whitespaces too.
+pub fn parse_version_list(list: &str, mode: TrimMode) -> Result<Vec<Version<'_>>, ParseError<'_>> {
+    Version::parse_separated(list, |c| c == ',', mode)
+}
+
+/// Extract package names and versions from `[[package]]` blocks of `Cargo.lock` like TOML text.
+/// This function finds `name = "..."` line followed by `version = "..."` line without full TOML parsing.
+/// Versions are parsed in lenient mode, and malformed entries are skipped.
+pub fn parse_lockfile_packages(text: &str) -> Vec<(String, Version<'_>)> {
+    let re = match Regex::parse(r#"(?m)^[ \t]*name[ \t]*=[ \t]*"(?P<name>[^"]+)"[ \t]*\r?\n[ \t]*version[ \t]*=[ \t]*"(?P<version>[^"]+)""#) {
+        Ok(re) => re,
+        Err(_) => return Vec::new(),
+    };
+    re.capture_iter(text)
+        .filter_map(|c| match (c.get("name"), c.get("version")) {
+            (Some(name), Some(version)) => match Version::parse(version.as_str(), false) {
+                Ok(v) => Some((name.as_str().to_string(), v)),
+                Err(_) => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Result of [`parse_bench`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchResult {
+    /// Number of parsed inputs.
+    pub count: usize,
+
+    /// Number of inputs parsed successfully.
+    pub successes: usize,
+
+    /// Number of inputs failed to parse.
+    pub failures: usize,
+
+    /// Time elapsed to parse all inputs.
+    pub elapsed: Duration,
+}
+
+/// Parse all versions and report counts and the elapsed time, for tuning the parser.
+pub fn parse_bench(versions: &[&str], strict: bool) -> BenchResult {
+    let start = Instant::now();
+    let successes = versions.iter().filter(|v| Version::parse(v, strict).is_ok()).count();
+    let elapsed = start.elapsed();
+
+    BenchResult {
+        count: versions.len(),
+        successes,
+        failures: versions.len() - successes,
+        elapsed,
+    }
+}
+
+/// Returns versions of the Cartesian product of the components for test matrices.
+/// Versions are ordered by major, then minor, then patch,
+/// following the order of given slices (e.g. `1.0.0`, `1.0.1`, `2.0.0`, `2.0.1`).
+pub fn version_matrix(majors: &[u64], minors: &[u64], patches: &[u64]) -> Vec<Version<'static>> {
+    majors.iter()
+        .flat_map(|major| minors.iter().map(move |minor| (*major, *minor)))
+        .flat_map(|(major, minor)| patches.iter().map(move |patch| Version::new(major, minor, *patch)))
+        .collect()
+}
+
+/// Kind of the change between two versions.
+/// Variants are ordered by significance, `None` is the lowest and `Major` is the highest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BumpType {
+    /// No change in `<version core>` and `<pre-release>`.
+    None,
+
+    /// Only `<pre-release>` changed.
+    PreRelease,
+
+    /// `<patch>` changed.
+    Patch,
+
+    /// `<minor>` changed.
+    Minor,
+
+    /// `<major>` changed.
+    Major,
+}
+
+impl fmt::Display for BumpType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            BumpType::None => write!(f, "none"),
+            BumpType::PreRelease => write!(f, "prerelease"),
+            BumpType::Patch => write!(f, "patch"),
+            BumpType::Minor => write!(f, "minor"),
+            BumpType::Major => write!(f, "major"),
+        }
+    }
+}
+
+/// The most significant part that differs between two versions.
+/// Unlike [`BumpType`], the difference of `<build>` is also reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionDiff {
+    /// `<major>` differs.
+    Major,
+
+    /// `<minor>` differs.
+    Minor,
+
+    /// `<patch>` differs.
+    Patch,
+
+    /// `<pre-release>` differs.
+    PreRelease,
+
+    /// Only `<build>` differs.
+    Build,
+
+    /// Versions are identical.
+    None,
+}
+
+// Transitions
+impl<'a> Version<'a> {
+    /// Returns the most significant part that differs between the versions.
+    /// `<build>` is ignored as same as precedence.
+    pub fn highest_change(&self, to: &Version) -> BumpType {
+        if self.major != to.major {
+            BumpType::Major
+        } else if self.minor != to.minor {
+            BumpType::Minor
+        } else if self.patch != to.patch {
+            BumpType::Patch
+        } else if self.pre_release != to.pre_release {
+            BumpType::PreRelease
+        } else {
+            BumpType::None
+        }
+    }
+
+    /// Returns the most significant part that differs between the versions,
+    /// in the order of major, minor, patch, pre-release and build.
+    pub fn diff(&self, other: &Version) -> VersionDiff {
+        match self.highest_change(other) {
+            BumpType::Major => VersionDiff::Major,
+            BumpType::Minor => VersionDiff::Minor,
+            BumpType::Patch => VersionDiff::Patch,
+            BumpType::PreRelease => VersionDiff::PreRelease,
+            BumpType::None if self.build != other.build => VersionDiff::Build,
+            BumpType::None => VersionDiff::None,
+        }
+    }
+
+    /// Returns versions `x.y.z` for `z` from the current patch to `up_to_patch` inclusive.
+    /// Generated versions have no pre-release and build. Empty if `up_to_patch` is lower than the current patch.
+    pub fn patch_range(&self, up_to_patch: u64) -> impl Iterator<Item=Version<'static>> {
+        let (major, minor) = (self.major, self.minor);
+        (self.patch..=up_to_patch).map(move |patch| Version::new(major, minor, patch))
+    }
+
+    /// Returns versions `x.y.0` for `y` from the current minor to `up_to_minor` inclusive.
+    /// Generated versions have no pre-release and build. Empty if `up_to_minor` is lower than the current minor.
+    pub fn minor_range(&self, up_to_minor: u64) -> impl Iterator<Item=Version<'static>> {
+        let major = self.major;
+        (self.minor..=up_to_minor).map(move |minor| Version::new(major, minor, 0))
+    }
+
+    /// Returns the version after the bump of `<version core>`, without pre-release and build.
+    /// Returns `None` if the bumped number overflows.
+    fn bumped(&self, bump: BumpType) -> Option<Version<'static>> {
+        match bump {
+            BumpType::Major => self.major.checked_add(1).map(|major| Version::new(major, 0, 0)),
+            BumpType::Minor => self.minor.checked_add(1).map(|minor| Version::new(self.major, minor, 0)),
+            BumpType::Patch => self.patch.checked_add(1).map(|patch| Version::new(self.major, self.minor, patch)),
+            BumpType::PreRelease | BumpType::None => Some(Version::new(self.major, self.minor, self.patch)),
+        }
+    }
+
+    /// Returns the minimal bump of patch, minor or major that results in a version greater than or equal to the `target`.
+    /// Returns `None` if this version is already greater than or equal to the `target`.
+    /// Returns `Major` if the target requires more than one major bump (e.g. `1.2.3` to `3.0.0`).
+    /// Bumps that overflow `u64` are skipped (e.g. `1.2.18446744073709551615` to `1.3.0` is `Minor`),
+    /// then returns `None` if every bump overflows.
+    pub fn minimal_bump_to(&self, target: &Version) -> Option<BumpType> {
+        if self.partial_cmp(target) != Some(Ordering::Less) {
+            return None;
+        }
+        [BumpType::Patch, BumpType::Minor, BumpType::Major]
+            .into_iter()
+            .find(|b| match self.bumped(*b) {
+                Some(_) if *b == BumpType::Major => true,
+                Some(v) => v.partial_cmp(target) != Some(Ordering::Less),
+                None => false,
+            })
+    }
+
+    /// Returns human-readable description of the transition like `1.2.3 → 2.0.0 (major bump)`.
+    /// The transition to the lower precedence is described as downgrade like `2.0.0 → 1.2.3 (major downgrade)`.
+    pub fn describe_transition(&self, to: &Version) -> String {
+        match (self.highest_change(to), self.partial_cmp(to)) {
+            (BumpType::None, _) => format!("{} → {} (no change)", self, to),
+            (bump, Some(Ordering::Greater)) => format!("{} → {} ({} downgrade)", self, to, bump),
+            (bump, _) => format!("{} → {} ({} bump)", self, to, bump),
+        }
+    }
+}
+
 /// Parse & return package version.
 /// This function will return additional information in the future,
 /// such as build numbers from CI.
@@ -248,11 +632,39 @@ pub fn package_version(v: Option<&str>) -> Version {
     }
 }
 
+/// Sort versions in ascending order of precedence.
+/// The sort is stable, then versions that differ only in `<build>` keep their order.
+pub fn sort_versions(versions: &mut [Version]) {
+    versions.sort_by(|x, y| x.partial_cmp(y).unwrap_or(Ordering::Equal));
+}
+
+/// Returns the highest precedence version that is not a pre-release.
+/// Returns `None` if the slice has no stable version.
+pub fn max_stable<'a, 'b>(versions: &'a [Version<'b>]) -> Option<&'a Version<'b>> {
+    versions
+        .iter()
+        .filter(|v| !v.is_prerelease())
+        .max_by(|x, y| x.partial_cmp(y).unwrap_or(Ordering::Equal))
+}
+
+/// Returns true if the versions share the same `<version core>` and
+/// the versions are strictly increasing by precedence (e.g. `1.0.0-alpha.1`, `1.0.0-alpha.2`, `1.0.0-beta.1`).
+/// Returns true for an empty or single element slice.
+pub fn is_increasing_prerelease_sequence(versions: &[Version]) -> bool {
+    versions.windows(2).all(|w| {
+        w[0].major == w[1].major &&
+            w[0].minor == w[1].minor &&
+            w[0].patch == w[1].patch &&
+            w[0].partial_cmp(&w[1]) == Some(Ordering::Less)
+    })
+}
+
 #[cfg(test)]
 mod version {
     use crate::text::version::semantic::build::Build;
     use crate::text::version::semantic::prerelease::PreRelease;
-    use crate::text::version::semantic::Version;
+    use crate::text::essential::TrimMode;
+    use crate::text::version::semantic::{json_string, is_increasing_prerelease_sequence, max_stable, sort_versions, parse_bench, parse_lockfile_packages, parse_version_list, version_matrix, BumpType, Version, VersionDiff};
 
     #[test]
     fn test_zero() {
@@ -392,4 +804,360 @@ mod version {
         };
         assert_eq!("1.2.3-beta+20221130", format!("{one_two_three_beta_build}"));
     }
+
+    #[test]
+    fn test_is_increasing_prerelease_sequence() {
+        let alpha_1 = Version::parse("1.0.0-alpha.1", true).unwrap();
+        let alpha_2 = Version::parse("1.0.0-alpha.2", true).unwrap();
+        let beta_1 = Version::parse("1.0.0-beta.1", true).unwrap();
+        let other_core = Version::parse("1.0.1-rc.1", true).unwrap();
+
+        assert!(is_increasing_prerelease_sequence(&[alpha_1.clone(), alpha_2.clone(), beta_1.clone()]));
+        assert!(!is_increasing_prerelease_sequence(&[alpha_2.clone(), alpha_1.clone()]));
+        assert!(!is_increasing_prerelease_sequence(&[alpha_1.clone(), alpha_1.clone()]));
+        assert!(!is_increasing_prerelease_sequence(&[alpha_1.clone(), beta_1.clone(), other_core]));
+        assert!(is_increasing_prerelease_sequence(&[alpha_1]));
+        assert!(is_increasing_prerelease_sequence(&[]));
+    }
+
+    #[test]
+    fn test_highest_change() {
+        let v1_2_3 = Version::parse("1.2.3", true).unwrap();
+        assert_eq!(BumpType::Major, v1_2_3.highest_change(&Version::parse("2.0.0", true).unwrap()));
+        assert_eq!(BumpType::Minor, v1_2_3.highest_change(&Version::parse("1.3.0", true).unwrap()));
+        assert_eq!(BumpType::Patch, v1_2_3.highest_change(&Version::parse("1.2.4", true).unwrap()));
+        assert_eq!(BumpType::PreRelease, v1_2_3.highest_change(&Version::parse("1.2.3-rc.1", true).unwrap()));
+        assert_eq!(BumpType::None, v1_2_3.highest_change(&Version::parse("1.2.3+20221208", true).unwrap()));
+    }
+
+    #[test]
+    fn test_describe_transition() {
+        let v1_2_3 = Version::parse("1.2.3", true).unwrap();
+        let v1_2_4 = Version::parse("1.2.4", true).unwrap();
+        let v2_0_0 = Version::parse("2.0.0", true).unwrap();
+
+        assert_eq!("1.2.3 → 2.0.0 (major bump)", v1_2_3.describe_transition(&v2_0_0));
+        assert_eq!("1.2.3 → 1.2.4 (patch bump)", v1_2_3.describe_transition(&v1_2_4));
+        assert_eq!("1.2.3 → 1.2.3 (no change)", v1_2_3.describe_transition(&v1_2_3));
+        assert_eq!("2.0.0 → 1.2.3 (major downgrade)", v2_0_0.describe_transition(&v1_2_3));
+    }
+
+    #[test]
+    fn test_parse_version_list() {
+        let vs = parse_version_list("1.0.0, 1.1.0,,\t2.0.0-beta ", TrimMode::Ascii).unwrap();
+        assert_eq!(vec![Version::new(1, 0, 0), Version::new(1, 1, 0), Version::parse("2.0.0-beta", true).unwrap()], vs);
+
+        assert!(parse_version_list("1.0.0, 1.1.0", TrimMode::None).is_err());
+        assert!(parse_version_list("1.0.0,\u{3000}1.1.0", TrimMode::Ascii).is_err());
+        assert_eq!(
+            vec![Version::new(1, 0, 0), Version::new(1, 1, 0)],
+            parse_version_list("1.0.0,\u{3000}1.1.0", TrimMode::Unicode).unwrap()
+        );
+        assert_eq!(Some(1), parse_version_list("1.0.0, x.y.z", TrimMode::Ascii).unwrap_err().entry());
+        assert_eq!(0, parse_version_list("", TrimMode::Ascii).unwrap().len());
+    }
+
+    #[test]
+    fn test_version_matrix() {
+        let m = version_matrix(&[1, 2], &[0], &[0, 1]);
+        assert_eq!(vec![Version::new(1, 0, 0), Version::new(1, 0, 1), Version::new(2, 0, 0), Version::new(2, 0, 1)], m);
+
+        assert!(version_matrix(&[1, 2], &[], &[0, 1]).is_empty());
+        assert_eq!(12, version_matrix(&[0, 1], &[0, 1, 2], &[0, 1]).len());
+    }
+
+    #[test]
+    fn test_parse_lockfile_packages() {
+        let lock = r#"# This file is automatically @generated by Cargo.
+version = 3
+
+[[package]]
+name = "aho-corasick"
+version = "0.7.20"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = [
+ "memchr",
+]
+
+[[package]]
+name = "broken"
+version = "one.two"
+
+[[package]]
+name = "tbx_essential"
+version = "0.2.3-beta.1"
+"#;
+        let packages = parse_lockfile_packages(lock);
+        assert_eq!(2, packages.len());
+        assert_eq!("aho-corasick", packages[0].0);
+        assert_eq!(Version::new(0, 7, 20), packages[0].1);
+        assert_eq!("tbx_essential", packages[1].0);
+        assert_eq!("0.2.3-beta.1", packages[1].1.to_string());
+
+        assert!(parse_lockfile_packages("").is_empty());
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        let versions = ["1.2.3", "1.0.0-alpha.1", "01.2.3", "1.2", "x.y.z", "1.0.0+20221208"];
+
+        let strict = parse_bench(&versions, true);
+        assert_eq!(6, strict.count);
+        assert_eq!(3, strict.successes);
+        assert_eq!(3, strict.failures);
+        assert!(!strict.elapsed.is_zero());
+
+        let lenient = parse_bench(&versions, false);
+        assert_eq!(4, lenient.successes);
+        assert_eq!(2, lenient.failures);
+
+        let empty = parse_bench(&[], true);
+        assert_eq!(0, empty.count);
+    }
+
+    #[test]
+    fn test_minimal_bump_to() {
+        let v1_2_3 = Version::parse("1.2.3", true).unwrap();
+        fn target(v: &str) -> Version<'_> {
+            Version::parse(v, true).unwrap()
+        }
+
+        assert_eq!(Some(BumpType::Patch), v1_2_3.minimal_bump_to(&target("1.2.4")));
+        assert_eq!(Some(BumpType::Minor), v1_2_3.minimal_bump_to(&target("1.2.5")));
+        assert_eq!(Some(BumpType::Minor), v1_2_3.minimal_bump_to(&target("1.3.0")));
+        assert_eq!(Some(BumpType::Major), v1_2_3.minimal_bump_to(&target("2.0.0")));
+        assert_eq!(Some(BumpType::Major), v1_2_3.minimal_bump_to(&target("3.0.0")));
+        assert_eq!(Some(BumpType::Patch), v1_2_3.minimal_bump_to(&target("1.2.4-beta.1")));
+        assert_eq!(None, v1_2_3.minimal_bump_to(&target("1.0.0")));
+        assert_eq!(None, v1_2_3.minimal_bump_to(&target("1.2.3")));
+
+        let rc = target("2.0.0-rc.1");
+        assert_eq!(Some(BumpType::Patch), rc.minimal_bump_to(&target("2.0.0")));
+        assert_eq!(Some(BumpType::Minor), rc.minimal_bump_to(&target("2.1.0")));
+
+        let max = u64::MAX;
+        assert_eq!(Some(BumpType::Minor), Version::new(1, 2, max).minimal_bump_to(&Version::new(1, 3, 0)));
+        assert_eq!(Some(BumpType::Major), Version::new(1, max, max).minimal_bump_to(&Version::new(2, 0, 0)));
+        assert_eq!(Some(BumpType::Patch), Version::new(max, max, max - 1).minimal_bump_to(&Version::new(max, max, max)));
+        assert_eq!(None, target("18446744073709551615.18446744073709551615.18446744073709551615-rc.1")
+            .minimal_bump_to(&Version::new(max, max, max)));
+    }
+
+    #[test]
+    fn test_clamp() {
+        let min = Version::new(1, 0, 0);
+        let max = Version::new(2, 0, 0);
+
+        assert_eq!(min, Version::new(0, 9, 0).clamp(&min, &max));
+        assert_eq!(max, Version::new(3, 0, 0).clamp(&min, &max));
+        assert_eq!(Version::new(1, 5, 0), Version::new(1, 5, 0).clamp(&min, &max));
+        assert_eq!(min, Version::parse("1.0.0-rc.1", true).unwrap().clamp(&min, &max));
+        assert_eq!(max, Version::new(2, 0, 0).clamp(&min, &max));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_invalid_range() {
+        Version::new(1, 0, 0).clamp(&Version::new(2, 0, 0), &Version::new(1, 0, 0));
+    }
+
+    #[test]
+    fn test_diff() {
+        fn v(v: &str) -> Version<'_> {
+            Version::parse(v, true).unwrap()
+        }
+
+        assert_eq!(VersionDiff::Major, v("1.0.0").diff(&v("2.0.0")));
+        assert_eq!(VersionDiff::Minor, v("1.0.0").diff(&v("1.1.0-rc.1")));
+        assert_eq!(VersionDiff::Patch, v("1.0.1").diff(&v("1.0.0")));
+        assert_eq!(VersionDiff::PreRelease, v("1.0.0").diff(&v("1.0.0-rc.1")));
+        assert_eq!(VersionDiff::PreRelease, v("1.0.0-rc.1+b1").diff(&v("1.0.0-rc.2+b2")));
+        assert_eq!(VersionDiff::Build, v("1.0.0+b1").diff(&v("1.0.0+b2")));
+        assert_eq!(VersionDiff::Build, v("1.0.0").diff(&v("1.0.0+b2")));
+        assert_eq!(VersionDiff::None, v("1.0.0-rc.1+b1").diff(&v("1.0.0-rc.1+b1")));
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(
+            r#"{"major":1,"minor":2,"patch":3,"pre_release":"rc.1","build":"exp.sha"}"#,
+            Version::parse("1.2.3-rc.1+exp.sha", true).unwrap().to_json()
+        );
+        assert_eq!(
+            r#"{"major":1,"minor":2,"patch":3,"pre_release":null,"build":null}"#,
+            Version::new(1, 2, 3).to_json()
+        );
+        assert_eq!(
+            r#"{"major":0,"minor":0,"patch":1,"pre_release":null,"build":"001"}"#,
+            Version::parse("0.0.1+001", true).unwrap().to_json()
+        );
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(r#""rc.1""#, json_string("rc.1"));
+        assert_eq!(r#""a\"b\\c""#, json_string(r#"a"b\c"#));
+        assert_eq!(r#""\n\t\u0001""#, json_string("\n\t\u{1}"));
+        assert_eq!(r#""ベータ""#, json_string("ベータ"));
+    }
+
+    #[test]
+    fn test_is_compatible_with() {
+        let v1_2_0 = Version::new(1, 2, 0);
+        assert!(v1_2_0.is_compatible_with(&Version::new(1, 9, 9)));
+        assert!(v1_2_0.is_compatible_with(&Version::new(1, 2, 0)));
+        assert!(!v1_2_0.is_compatible_with(&Version::new(2, 0, 0)));
+        assert!(!v1_2_0.is_compatible_with(&Version::new(1, 1, 9)));
+        assert!(!v1_2_0.is_compatible_with(&Version::parse("1.2.0-rc.1", true).unwrap()));
+
+        let v0_2_0 = Version::new(0, 2, 0);
+        assert!(v0_2_0.is_compatible_with(&Version::new(0, 2, 9)));
+        assert!(!v0_2_0.is_compatible_with(&Version::new(0, 3, 0)));
+        assert!(!v0_2_0.is_compatible_with(&Version::new(1, 2, 0)));
+
+        let v0_0_3 = Version::new(0, 0, 3);
+        assert!(v0_0_3.is_compatible_with(&Version::new(0, 0, 3)));
+        assert!(v0_0_3.is_compatible_with(&Version::parse("0.0.3+build", true).unwrap()));
+        assert!(!v0_0_3.is_compatible_with(&Version::new(0, 0, 4)));
+        assert!(!v0_0_3.is_compatible_with(&Version::new(0, 1, 0)));
+
+        let rc = Version::parse("1.2.0-rc.1", true).unwrap();
+        assert!(rc.is_compatible_with(&Version::parse("1.2.0-rc.2", true).unwrap()));
+        assert!(rc.is_compatible_with(&Version::new(1, 2, 0)));
+        assert!(rc.is_compatible_with(&Version::new(1, 3, 0)));
+        assert!(!rc.is_compatible_with(&Version::parse("1.3.0-beta", true).unwrap()));
+        assert!(!v1_2_0.is_compatible_with(&Version::parse("1.3.0-beta", true).unwrap()));
+    }
+
+    #[test]
+    fn test_release_line() {
+        assert_eq!((1, 2), Version::new(1, 2, 3).release_line());
+        assert_eq!((0, 0), Version::zero().release_line());
+        assert_eq!((1, 2), Version::parse("1.2.3-rc.1+build", true).unwrap().release_line());
+
+        let v1_2_3 = Version::new(1, 2, 3);
+        assert!(v1_2_3.same_minor_line(&Version::new(1, 2, 9)));
+        assert!(v1_2_3.same_minor_line(&Version::new(1, 2, 0)));
+        assert!(v1_2_3.same_minor_line(&Version::parse("1.2.4-beta", true).unwrap()));
+        assert!(!v1_2_3.same_minor_line(&Version::new(1, 3, 0)));
+        assert!(!v1_2_3.same_minor_line(&Version::new(2, 2, 3)));
+    }
+
+    #[test]
+    fn test_max_stable() {
+        let versions = [
+            Version::parse("1.0.0", true).unwrap(),
+            Version::parse("1.1.0-rc.1", true).unwrap(),
+            Version::parse("1.0.9", true).unwrap(),
+        ];
+        assert_eq!(Some(&Version::new(1, 0, 9)), max_stable(&versions));
+        assert!(versions[1].is_prerelease());
+        assert!(!versions[0].is_prerelease());
+
+        let pre = [Version::parse("1.0.0-alpha", true).unwrap()];
+        assert_eq!(None, max_stable(&pre));
+        assert_eq!(None, max_stable(&[]));
+    }
+
+    #[test]
+    fn test_sort_versions() {
+        let mut versions = vec![
+            Version::parse("1.0.0", true).unwrap(),
+            Version::parse("1.1.0-rc.1", true).unwrap(),
+            Version::parse("0.9.0", true).unwrap(),
+            Version::parse("1.0.9", true).unwrap(),
+            Version::parse("1.0.0-beta.11", true).unwrap(),
+            Version::parse("1.0.0-beta.2", true).unwrap(),
+        ];
+        sort_versions(&mut versions);
+        assert_eq!(
+            "0.9.0, 1.0.0-beta.2, 1.0.0-beta.11, 1.0.0, 1.0.9, 1.1.0-rc.1",
+            versions.iter().map(|v| v.to_string()).collect::<Vec<String>>().join(", ")
+        );
+    }
+
+    #[test]
+    fn test_patch_range() {
+        let to_strings = |vs: Vec<Version>| vs.iter().map(|v| v.to_string()).collect::<Vec<String>>();
+
+        let v = Version::new(1, 0, 0);
+        assert_eq!(vec!["1.0.0", "1.0.1", "1.0.2", "1.0.3"], to_strings(v.patch_range(3).collect()));
+        assert_eq!(vec!["1.0.0"], to_strings(v.patch_range(0).collect()));
+
+        let rc = Version::parse("1.2.3-rc.1+b1", true).unwrap();
+        assert_eq!(vec!["1.2.3", "1.2.4"], to_strings(rc.patch_range(4).collect()));
+        assert_eq!(0, rc.patch_range(2).count());
+    }
+
+    #[test]
+    fn test_minor_range() {
+        let to_strings = |vs: Vec<Version>| vs.iter().map(|v| v.to_string()).collect::<Vec<String>>();
+
+        let v = Version::new(1, 2, 3);
+        assert_eq!(vec!["1.2.0", "1.3.0", "1.4.0"], to_strings(v.minor_range(4).collect()));
+        assert_eq!(0, v.minor_range(1).count());
+    }
+
+    #[test]
+    fn test_identifiers() {
+        let v = Version::parse("1.0.0-rc.1+exp.sha", true).unwrap();
+        assert_eq!(Some(&["rc", "1"][..]), v.pre_release_identifiers());
+        assert_eq!(Some(&["exp", "sha"][..]), v.build_identifiers());
+
+        let rc: u64 = v.pre_release_identifiers().and_then(|ids| ids.get(1)).unwrap().parse().unwrap();
+        assert_eq!(1, rc);
+
+        assert_eq!(None, Version::new(1, 0, 0).pre_release_identifiers());
+        assert_eq!(None, Version::new(1, 0, 0).build_identifiers());
+    }
+
+    #[test]
+    fn test_parse_strict_lenient() {
+        assert!(Version::parse_strict("01.0.0").is_err());
+        assert_eq!(Version::new(1, 0, 0), Version::parse_lenient("01.0.0").unwrap());
+
+        assert!(Version::parse_strict("1.0.0-rc.01").is_err());
+        assert_eq!("1.0.0-rc.01", Version::parse_lenient("1.0.0-rc.01").unwrap().to_string());
+
+        assert_eq!(Version::new(1, 2, 3), Version::parse_strict("1.2.3").unwrap());
+        assert!(Version::parse_lenient("1.2").is_err());
+    }
+
+    #[test]
+    fn test_parse_trimmed() {
+        assert_eq!(Version::new(1, 2, 3), Version::parse_trimmed(" 1.2.3 ", true).unwrap());
+        assert_eq!("1.2.3-rc.1+build", Version::parse_trimmed("\t1.2.3-rc.1+build\r\n", true).unwrap().to_string());
+        assert_eq!(Version::new(1, 0, 0), Version::parse_trimmed(" 01.0.0", false).unwrap());
+        assert!(Version::parse_trimmed(" 01.0.0", true).is_err());
+        assert!(Version::parse_trimmed("1.2. 3", true).is_err());
+        assert!(Version::parse_trimmed("   ", true).is_err());
+        assert!(Version::parse_trimmed(" 18446744073709551616.0.0 ", true).is_err());
+
+        assert!(Version::parse(" 1.2.3 ", true).is_err());
+        assert!(Version::parse("1.2.3 ", false).is_err());
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!("Version(\"1.2.3-rc.1\")", format!("{:?}", Version::parse("1.2.3-rc.1", true).unwrap()));
+        assert_eq!("Version(\"1.2.3-rc.1+build\")", format!("{:?}", Version::parse("1.2.3-rc.1+build", true).unwrap()));
+        assert_eq!("Some(Version(\"0.0.0\"))", format!("{:?}", Some(Version::zero())));
+
+        let alt = format!("{:#?}", Version::parse("1.2.3-rc.1", true).unwrap());
+        assert!(alt.starts_with("Version {\n    major: 1,\n    minor: 2,\n    patch: 3,\n"));
+        assert!(alt.contains("pre_release: Some("));
+        assert!(alt.contains("build: None"));
+    }
+
+    #[test]
+    fn test_parse_list() {
+        let vs = Version::parse_list("1.0.0, 1.1.0 2.0.0").unwrap();
+        assert_eq!(vec![Version::new(1, 0, 0), Version::new(1, 1, 0), Version::new(2, 0, 0)], vs);
+        assert_eq!(2, Version::parse_list(" ,1.0.0,,\t01.0.0-rc.1\n").unwrap().len());
+        assert!(Version::parse_list("").unwrap().is_empty());
+
+        let e = Version::parse_list("1.0.0, 1.x.0, 2.0.0").unwrap_err();
+        assert_eq!(Some(1), e.entry());
+        assert!(e.to_string().ends_with(" at list entry 1"));
+    }
 }
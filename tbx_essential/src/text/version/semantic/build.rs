@@ -30,6 +30,11 @@ impl<'a> Build<'a> {
         })
     }
 
+    /// Returns dot separated identifiers like `["exp", "sha"]` of `exp.sha`.
+    pub fn identifiers(&self) -> &[&'a str] {
+        &self.build
+    }
+
     fn parse_build_identifier(build: &'a str, strict: bool) -> Result<&'a str, ParseError> {
         if let Ok(id) = parse::parse_alphanumeric_identifier(build, strict) {
             Ok(id)
@@ -70,6 +75,12 @@ impl<'a> PartialEq<Self> for Build<'a> {
 mod build {
     use crate::text::version::semantic::build::Build;
 
+    #[test]
+    fn test_identifiers() {
+        assert_eq!(["exp", "sha"], Build::parse("exp.sha", true).unwrap().identifiers());
+        assert_eq!(["20130313144700"], Build::parse("20130313144700", true).unwrap().identifiers());
+    }
+
     #[test]
     fn test_parse() {
         let valid_builds = [
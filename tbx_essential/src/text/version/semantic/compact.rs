@@ -0,0 +1,195 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use crate::text::version::semantic::Version;
+
+/// `<version core>` stored in `u32` per component.
+/// Components exceeding `u32` are widened to `u64` on the heap to keep the enum small.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CompactCore {
+    U32([u32; 3]),
+    U64(Box<[u64; 3]>),
+}
+
+/// Pool of the suffixes (`<pre-release>` and `<build>`) to share among [`CompactVersion`]s.
+/// The pool is owned by the caller, and keeps suffixes until the pool is dropped.
+#[derive(Debug, Default)]
+pub struct SuffixPool {
+    suffixes: HashSet<Arc<str>>,
+}
+
+impl SuffixPool {
+    /// Create an empty pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of distinct suffixes in the pool.
+    pub fn len(&self) -> usize {
+        self.suffixes.len()
+    }
+
+    /// Returns true if the pool has no suffix.
+    pub fn is_empty(&self) -> bool {
+        self.suffixes.is_empty()
+    }
+
+    /// Returns the shared instance of the suffix.
+    fn intern(&mut self, suffix: String) -> Arc<str> {
+        if let Some(s) = self.suffixes.get(suffix.as_str()) {
+            return s.clone();
+        }
+        let s: Arc<str> = Arc::from(suffix);
+        self.suffixes.insert(s.clone());
+        s
+    }
+}
+
+/// Memory efficient representation of [`Version`] for holding a large number of versions.
+///
+/// [`Version`] takes 72 bytes on 64-bit platforms (`u64` per component and vectors of
+/// pre-release/build identifiers) plus heap allocations for the identifiers.
+/// `CompactVersion` takes 32 bytes, storing `<version core>` in `u32` per component
+/// and `<pre-release>`/`<build>` as a single reference counted string.
+/// Versions converted with the same [`SuffixPool`] share one allocation for the same suffix like `-beta`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactVersion {
+    core: CompactCore,
+
+    /// Text after `<version core>` like `-alpha.1+20221208`.
+    suffix: Option<Arc<str>>,
+}
+
+impl CompactVersion {
+    /// Returns `<major>`.
+    pub fn major(&self) -> u64 {
+        self.core()[0]
+    }
+
+    /// Returns `<minor>`.
+    pub fn minor(&self) -> u64 {
+        self.core()[1]
+    }
+
+    /// Returns `<patch>`.
+    pub fn patch(&self) -> u64 {
+        self.core()[2]
+    }
+
+    fn core(&self) -> [u64; 3] {
+        match &self.core {
+            CompactCore::U32(c) => c.map(u64::from),
+            CompactCore::U64(c) => **c,
+        }
+    }
+
+    /// Restore the version. Pre-release and build identifiers refer to this instance.
+    pub fn to_version(&self) -> Version<'_> {
+        let [major, minor, patch] = self.core();
+        let (pre_release, build) = match &self.suffix {
+            // the suffix was taken from a valid version
+            Some(s) => Version::parse_pre_release_and_build(s, false).unwrap_or((None, None)),
+            None => (None, None),
+        };
+        Version { major, minor, patch, pre_release, build }
+    }
+}
+
+impl CompactVersion {
+    /// Convert the version, sharing the suffix with other versions converted with the same pool.
+    pub fn from_version_in(v: Version, pool: &mut SuffixPool) -> Self {
+        Self::from_version_with(v, |suffix| pool.intern(suffix))
+    }
+
+    fn from_version_with(v: Version, mut to_shared: impl FnMut(String) -> Arc<str>) -> Self {
+        let c = [v.major, v.minor, v.patch];
+        let core = if c.iter().all(|x| *x <= u32::MAX as u64) {
+            CompactCore::U32(c.map(|x| x as u32))
+        } else {
+            CompactCore::U64(Box::new(c))
+        };
+        let suffix = match (&v.pre_release, &v.build) {
+            (Some(pre), Some(build)) => Some(format!("-{}+{}", pre, build)),
+            (Some(pre), None) => Some(format!("-{}", pre)),
+            (None, Some(build)) => Some(format!("+{}", build)),
+            (None, None) => None,
+        }.map(&mut to_shared);
+        CompactVersion { core, suffix }
+    }
+}
+
+impl<'a> From<Version<'a>> for CompactVersion {
+    fn from(v: Version<'a>) -> Self {
+        Self::from_version_with(v, Arc::from)
+    }
+}
+
+impl fmt::Display for CompactVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}{}", self.major(), self.minor(), self.patch(), self.suffix.as_deref().unwrap_or(""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::size_of;
+    use std::sync::Arc;
+    use crate::text::version::semantic::compact::{CompactCore, CompactVersion, SuffixPool};
+    use crate::text::version::semantic::Version;
+
+    #[test]
+    fn test_size() {
+        assert!(size_of::<CompactVersion>() < size_of::<Version>());
+        assert!(size_of::<CompactVersion>() <= 32);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for v in ["0.0.0", "1.2.3", "1.0.0-alpha.1", "1.0.0+20221208", "1.0.0-beta.11+exp.sha", "300.2.1", "1.70000.0"] {
+            let original = Version::parse(v, true).unwrap();
+            let compact = original.clone().into_compact();
+            assert_eq!(original, compact.to_version());
+            assert_eq!(v, compact.to_string());
+        }
+    }
+
+    #[test]
+    fn test_suffix_pool() {
+        let mut pool = SuffixPool::new();
+        assert!(pool.is_empty());
+        let a = Version::parse("1.2.3-beta", true).unwrap().into_compact_in(&mut pool);
+        let b = Version::parse("4.5.6-beta", true).unwrap().into_compact_in(&mut pool);
+        let c = Version::parse("1.2.3-rc.1", true).unwrap().into_compact_in(&mut pool);
+        let d = Version::new(1, 2, 3).into_compact_in(&mut pool);
+        assert!(Arc::ptr_eq(a.suffix.as_ref().unwrap(), b.suffix.as_ref().unwrap()));
+        assert!(!Arc::ptr_eq(a.suffix.as_ref().unwrap(), c.suffix.as_ref().unwrap()));
+        assert_eq!(None, d.suffix);
+        assert_eq!(2, pool.len());
+        assert_eq!("4.5.6-beta", b.to_string());
+
+        // without the pool, suffixes are not shared
+        let e = Version::parse("1.2.3-beta", true).unwrap().into_compact();
+        assert!(!Arc::ptr_eq(a.suffix.as_ref().unwrap(), e.suffix.as_ref().unwrap()));
+        assert_eq!(a, e);
+    }
+
+    #[test]
+    fn test_widen() {
+        let c = Version::new(1, 2, 3).into_compact();
+        assert!(matches!(c.core, CompactCore::U32(_)));
+
+        let c = Version::new(70000, 2, u32::MAX as u64).into_compact();
+        assert!(matches!(c.core, CompactCore::U32(_)));
+
+        let c = Version::new(u32::MAX as u64 + 1, 2, 3).into_compact();
+        assert!(matches!(c.core, CompactCore::U64(_)));
+        assert_eq!(u32::MAX as u64 + 1, c.major());
+
+        let c = Version::new(1, 2, u64::MAX).into_compact();
+        assert!(matches!(c.core, CompactCore::U64(_)));
+        assert_eq!(u64::MAX, c.patch());
+        assert_eq!(Version::new(1, 2, u64::MAX), c.to_version());
+    }
+}
@@ -1,3 +1,4 @@
+use std::error::Error;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug)]
@@ -67,6 +68,7 @@ impl<'a> Display for ParseErrorReason<'a> {
 pub struct ParseError<'a> {
     part: ParseInvalidPart,
     reason: ParseErrorReason<'a>,
+    entry: Option<usize>,
 }
 
 impl<'a> ParseError<'a> {
@@ -74,21 +76,42 @@ impl<'a> ParseError<'a> {
         ParseError {
             part,
             reason,
+            entry: None,
         }
     }
+
+    /// Returns the error with the index (0-based) of the failed entry in a list of versions.
+    pub fn with_entry(self, index: usize) -> ParseError<'a> {
+        ParseError {
+            entry: Some(index),
+            ..self
+        }
+    }
+
+    /// Index (0-based) of the failed entry, if the error occurred while parsing a list of versions.
+    pub fn entry(&self) -> Option<usize> {
+        self.entry
+    }
 }
 
 impl<'a> Display for ParseError<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match &self.part {
-            ParseInvalidPart::Other => write!(f, "{}", self.reason),
-            _ => write!(f, "{} in part {:?}", self.reason, self.part),
+            ParseInvalidPart::Other => write!(f, "{}", self.reason)?,
+            _ => write!(f, "{} in part {:?}", self.reason, self.part)?,
+        }
+        match self.entry {
+            Some(i) => write!(f, " at list entry {}", i),
+            None => Ok(()),
         }
     }
 }
 
+impl<'a> Error for ParseError<'a> {}
+
 #[cfg(test)]
 mod errors {
+    use std::error::Error;
     use crate::text::version::semantic::error::{ParseError, ParseErrorReason, ParseInvalidChar, ParseInvalidPart};
 
     #[test]
@@ -101,5 +124,15 @@ mod errors {
                    format!("{}",
                            ParseError::new(ParseInvalidPart::Other,
                                            ParseErrorReason::InvalidChar(ParseInvalidChar::from('*')))));
+        assert_eq!("invalid pattern in part Major at list entry 2",
+                   format!("{}",
+                           ParseError::new(ParseInvalidPart::Major, ParseErrorReason::InvalidPattern).with_entry(2)));
+    }
+
+    #[test]
+    fn test_error_parse_error() {
+        let e: Box<dyn Error> = Box::new(ParseError::new(ParseInvalidPart::Other, ParseErrorReason::InvalidPattern));
+        assert_eq!("invalid pattern", e.to_string());
+        assert!(e.source().is_none());
     }
-}
\ No newline at end of file
+}
@@ -56,12 +56,20 @@ impl<'a> PreRelease<'a> {
         }
     }
 
+    /// Returns dot separated identifiers like `["rc", "1"]` of `rc.1`.
+    pub fn identifiers(&self) -> &[&'a str] {
+        &self.pre_release
+    }
+
     fn parse_pre_release_identifier(pre: &str, strict: bool) -> Result<&str, ParseError> {
         // <pre-release identifier> ::= <alphanumeric identifier>
         //                            | <numeric identifier>
         // CC-BY 3.0, https://semver.org
 
-        if let Ok(id) = parse::parse_alphanumeric_identifier(pre, strict) {
+        // Identifiers MUST NOT be empty (e.g. `alpha..1`), regardless of `strict`.
+        if pre.is_empty() {
+            Err(ParseError::new(ParseInvalidPart::PreRelease, ParseErrorReason::InvalidPattern))
+        } else if let Ok(id) = parse::parse_alphanumeric_identifier(pre, strict) {
             Ok(id)
         } else if let Ok(id) = parse::parse_numeric_identifier(pre, strict) {
             Ok(id)
@@ -105,27 +113,12 @@ impl<'a> PartialOrd<Self> for PreRelease<'a> {
     /// >    if all of the preceding identifiers are equal.
     /// > Example: 1.0.0-alpha < 1.0.0-alpha.1 < 1.0.0-alpha.beta < 1.0.0-beta < 1.0.0-beta.2 < 1.0.0-beta.11 < 1.0.0-rc.1 < 1.0.0.
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        for (i, vx) in self.pre_release.iter().enumerate() {
-            match other.pre_release.get(i) {
-                Some(vy) => {
-                    let vc = compare::cmp_pre_release(vx, vy);
-                    if vc == Ordering::Equal {
-                        continue;
-                    } else {
-                        return Some(vc);
-                    }
-                }
-                None =>
-                    return Some(Ordering::Greater)
-            }
-        }
-        if self.pre_release.len() == other.pre_release.len() {
-            Some(Ordering::Equal)
-        } else if self.pre_release.len() < other.pre_release.len() {
-            Some(Ordering::Less)
-        } else {
-            Some(Ordering::Greater)
-        }
+        // Compare identifiers in the common prefix first (rules 1-3), then the number of identifiers (rule 4).
+        let identifiers = self.pre_release.iter()
+            .zip(other.pre_release.iter())
+            .map(|(vx, vy)| compare::cmp_pre_release(vx, vy))
+            .find(|c| *c != Ordering::Equal);
+        Some(identifiers.unwrap_or_else(|| self.pre_release.len().cmp(&other.pre_release.len())))
     }
 }
 
@@ -162,6 +155,22 @@ mod pre_release {
         }
     }
 
+    #[test]
+    fn test_parse_empty_identifier() {
+        for p in ["alpha..1", ".alpha", "alpha.", ".", ""] {
+            for strict in [true, false] {
+                assert!(PreRelease::parse(p, strict).is_err(), "{} (strict: {})", p, strict);
+            }
+        }
+        assert!(PreRelease::parse("alpha.1", false).is_ok());
+    }
+
+    #[test]
+    fn test_identifiers() {
+        assert_eq!(["rc", "1"], PreRelease::parse("rc.1", true).unwrap().identifiers());
+        assert_eq!(["alpha"], PreRelease::parse("alpha", true).unwrap().identifiers());
+    }
+
     #[test]
     fn test_eq() {
         let x_alpha1 = PreRelease::parse("alpha1", true).unwrap();
@@ -213,4 +222,35 @@ mod pre_release {
         assert_eq!(x_beta_11.partial_cmp(&x_rc_1).unwrap(), Ordering::Less);
         assert_eq!(x_rc_1.partial_cmp(&x_beta_11).unwrap(), Ordering::Greater);
     }
+
+    #[test]
+    fn test_partial_cmp_chain() {
+        let chain: Vec<PreRelease> = ["alpha", "alpha.1", "alpha.beta", "beta", "beta.2", "beta.11", "rc.1"]
+            .iter()
+            .map(|p| PreRelease::parse(p, true).unwrap())
+            .collect();
+
+        for (i, x) in chain.iter().enumerate() {
+            for (j, y) in chain.iter().enumerate() {
+                assert_eq!(x.partial_cmp(y), Some(i.cmp(&j)), "{} vs {}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_partial_cmp_prefix() {
+        let cmp = |x: &str, y: &str| PreRelease::parse(x, true).unwrap().partial_cmp(&PreRelease::parse(y, true).unwrap());
+
+        // equal length but different
+        assert_eq!(cmp("alpha.1", "alpha.2"), Some(Ordering::Less));
+        assert_eq!(cmp("alpha.2", "alpha.1"), Some(Ordering::Greater));
+
+        // longer set with the shared prefix has the higher precedence, regardless of the side
+        assert_eq!(cmp("alpha.1.2", "alpha.1"), Some(Ordering::Greater));
+        assert_eq!(cmp("alpha.1", "alpha.1.2"), Some(Ordering::Less));
+
+        // difference in the prefix wins over the length
+        assert_eq!(cmp("alpha.2", "alpha.1.0"), Some(Ordering::Greater));
+        assert_eq!(cmp("alpha.1.0", "alpha.2"), Some(Ordering::Less));
+    }
 }
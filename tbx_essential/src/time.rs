@@ -0,0 +1,5 @@
+pub mod calendar;
+pub mod duration;
+pub mod epoch;
+pub mod error;
+pub mod monotonic;
@@ -0,0 +1,108 @@
+pub mod gregorian;
+
+use std::borrow::Cow;
+
+use crate::time::calendar::gregorian::GregorianDateTime;
+use crate::time::duration::Duration;
+use crate::time::error::TimeError;
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+/// Date and time in a calendar system, with the offset from UTC.
+/// Fields are local date and time of the offset.
+pub trait Calendar {
+    /// Year like `2022`.
+    fn year(&self) -> i64;
+
+    /// Month in `1..=12`.
+    fn month(&self) -> u8;
+
+    /// Day of month in `1..=31`.
+    fn day(&self) -> u8;
+
+    /// Hour in `0..=23`.
+    fn hour(&self) -> u8;
+
+    /// Minute in `0..=59`.
+    fn minute(&self) -> u8;
+
+    /// Second in `0..=59`.
+    fn second(&self) -> u8;
+
+    /// Fractional part of the second in nanoseconds.
+    fn nanosecond(&self) -> u32;
+
+    /// Day of the week in `0..=6`, where `0` is Sunday.
+    fn day_of_week(&self) -> u8;
+
+    /// Offset from UTC in seconds like `32400` for `+09:00`.
+    fn offset_seconds(&self) -> i64;
+
+    /// Seconds since the Unix epoch (1970-01-01T00:00:00Z), taking the offset into account.
+    /// The fractional part of the second is ignored.
+    fn to_epoch_seconds(&self) -> i64;
+
+    /// Returns RFC 3339 `date-time` format with the offset like `2022-12-27T09:30:00+09:00`.
+    /// UTC is formatted with `Z` like `2022-12-27T00:30:00Z`.
+    fn to_rfc3339_date_time_offset<'a>(&self) -> Cow<'a, str>;
+
+    /// Returns RFC 2822 date and time format like `Tue, 27 Dec 2022 09:30:00 +0900`.
+    /// The fractional part of the second is ignored.
+    /// This is the HTTP-date format (RFC 7231 IMF-fixdate) if the offset is UTC, except the zone is `+0000` instead of `GMT`.
+    fn to_rfc2822<'a>(&self) -> Cow<'a, str>;
+
+    /// Returns the date and time after the duration, in the same offset.
+    /// Returns [`TimeError::Overflow`] if the result is out of the range of [`GregorianDateTime`].
+    fn add_duration(&self, d: &Duration) -> Result<GregorianDateTime, TimeError> {
+        let nanos = self.nanosecond() as u64 + d.subsec_nanos() as u64;
+        i64::try_from(d.as_secs()).ok()
+            .and_then(|s| self.to_epoch_seconds().checked_add(s))
+            .and_then(|s| s.checked_add((nanos / NANOS_PER_SECOND) as i64))
+            .and_then(|s| GregorianDateTime::from_epoch_seconds(s, (nanos % NANOS_PER_SECOND) as u32, self.offset_seconds()))
+            .ok_or(TimeError::Overflow)
+    }
+}
+
+/// Returns RFC 3339 `date-time` format of seconds since the Unix epoch, represented at the offset.
+/// See [`Calendar::to_rfc3339_date_time_offset`] for the format.
+/// Returns `None` if the offset or the result is out of range (see [`GregorianDateTime::from_epoch_seconds`]).
+pub fn from_unix_seconds(secs: i64, offset_seconds: i64) -> Option<String> {
+    GregorianDateTime::from_epoch_seconds(secs, 0, offset_seconds).map(|t| t.to_rfc3339_date_time_offset().into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::time::calendar::{from_unix_seconds, Calendar};
+    use crate::time::calendar::gregorian::GregorianDateTime;
+    use crate::time::duration::Duration;
+    use crate::time::error::TimeError;
+
+    #[test]
+    fn test_add_duration() {
+        let t = GregorianDateTime::new(2022, 12, 27, 10, 30, 0, 0).unwrap();
+        assert_eq!("2022-12-27T12:00:00Z", t.add_duration(&Duration::from_secs(90 * 60)).unwrap().to_rfc3339_date_time_offset());
+        assert_eq!(Err(TimeError::Overflow), t.add_duration(&Duration::from_secs(u64::MAX)));
+        assert_eq!(Err(TimeError::Overflow), t.add_duration(&Duration::from_secs(i64::MAX as u64)));
+        assert_eq!("time overflow", t.add_duration(&Duration::from_secs(u64::MAX)).unwrap_err().to_string());
+
+        let t = GregorianDateTime::new(2022, 12, 31, 23, 59, 59, 9 * 3600).unwrap();
+        assert_eq!("2023-01-01T00:00:00.5+09:00", t.add_duration(&Duration::from_millis(1_500)).unwrap().to_rfc3339_date_time_offset());
+
+        // the result owns its fields, then outlives the receiver
+        let t = {
+            let base = t;
+            base.add_duration(&Duration::from_millis(700)).unwrap()
+        };
+        assert_eq!("2023-01-01T00:00:01+09:00", t.add_duration(&Duration::from_millis(1_300)).unwrap().to_rfc3339_date_time_offset());
+    }
+
+    #[test]
+    fn test_from_unix_seconds() {
+        assert_eq!("1970-01-01T00:00:00Z", from_unix_seconds(0, 0).unwrap());
+        assert_eq!("1970-01-01T09:00:00+09:00", from_unix_seconds(0, 9 * 3600).unwrap());
+        assert_eq!("1969-12-31T19:00:00-05:00", from_unix_seconds(0, -5 * 3600).unwrap());
+        assert_eq!("2022-02-22T19:22:22Z", from_unix_seconds(1_645_557_742, 0).unwrap());
+        assert_eq!(None, from_unix_seconds(i64::MAX, 1));
+        assert_eq!(None, from_unix_seconds(0, 86_400));
+    }
+}
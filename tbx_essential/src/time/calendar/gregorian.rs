@@ -0,0 +1,267 @@
+use std::borrow::Cow;
+
+use crate::time::calendar::Calendar;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Range of the year, narrower than `i64` to keep seconds since the Unix epoch in `i64`.
+const YEAR_RANGE: std::ops::RangeInclusive<i64> = -100_000_000_000..=100_000_000_000;
+
+const DAY_OF_WEEK_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+const MONTH_NAMES: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+/// Date and time in the proleptic Gregorian calendar with the offset from UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GregorianDateTime {
+    year: i64,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+    offset_seconds: i64,
+}
+
+/// Returns true if the year is a leap year.
+pub fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns number of days of the month.
+pub fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Days since 1970-01-01 of the date.
+/// see: <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let m = month as i64;
+    let doy = (153 * (if 2 < m { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Date (year, month, day) of the days since 1970-01-01. Inverse of [`days_from_civil`].
+/// see: <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u8;
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    (year, month, day)
+}
+
+impl GregorianDateTime {
+    /// Create new instance of the local date and time at the offset.
+    /// Returns `None` if any field is out of range. The year must be in `-100_000_000_000..=100_000_000_000`.
+    pub fn new(year: i64, month: u8, day: u8, hour: u8, minute: u8, second: u8, offset_seconds: i64) -> Option<Self> {
+        if !YEAR_RANGE.contains(&year) || !(1..=12).contains(&month) || day < 1 || days_in_month(year, month) < day ||
+            23 < hour || 59 < minute || 59 < second || SECONDS_PER_DAY <= offset_seconds.abs() {
+            None
+        } else {
+            Some(Self { year, month, day, hour, minute, second, nanosecond: 0, offset_seconds })
+        }
+    }
+
+    /// Create new instance from seconds since the Unix epoch, represented at the offset.
+    /// Returns `None` if the offset or the nanosecond is out of range as same as [`Self::new`],
+    /// or the year is out of range.
+    pub fn from_epoch_seconds(secs: i64, nanosecond: u32, offset_seconds: i64) -> Option<Self> {
+        if SECONDS_PER_DAY <= offset_seconds.abs() || 1_000_000_000 <= nanosecond {
+            return None;
+        }
+        let local = secs.checked_add(offset_seconds)?;
+        let (year, month, day) = civil_from_days(local.div_euclid(SECONDS_PER_DAY));
+        if !YEAR_RANGE.contains(&year) {
+            return None;
+        }
+        let sod = local.rem_euclid(SECONDS_PER_DAY);
+        Some(Self {
+            year,
+            month,
+            day,
+            hour: (sod / 3600) as u8,
+            minute: (sod % 3600 / 60) as u8,
+            second: (sod % 60) as u8,
+            nanosecond,
+            offset_seconds,
+        })
+    }
+}
+
+impl Calendar for GregorianDateTime {
+    fn year(&self) -> i64 {
+        self.year
+    }
+
+    fn month(&self) -> u8 {
+        self.month
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    fn second(&self) -> u8 {
+        self.second
+    }
+
+    fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    fn day_of_week(&self) -> u8 {
+        // 1970-01-01 is Thursday
+        (days_from_civil(self.year, self.month, self.day) + 4).rem_euclid(7) as u8
+    }
+
+    fn offset_seconds(&self) -> i64 {
+        self.offset_seconds
+    }
+
+    fn to_epoch_seconds(&self) -> i64 {
+        days_from_civil(self.year, self.month, self.day) * SECONDS_PER_DAY
+            + self.hour as i64 * 3600
+            + self.minute as i64 * 60
+            + self.second as i64
+            - self.offset_seconds
+    }
+
+    fn to_rfc3339_date_time_offset<'a>(&self) -> Cow<'a, str> {
+        let frac = if self.nanosecond == 0 {
+            String::new()
+        } else {
+            format!(".{:09}", self.nanosecond).trim_end_matches('0').to_string()
+        };
+        let offset = if self.offset_seconds == 0 {
+            "Z".to_string()
+        } else {
+            let sign = if self.offset_seconds < 0 { '-' } else { '+' };
+            let abs = self.offset_seconds.abs();
+            format!("{}{:02}:{:02}", sign, abs / 3600, abs % 3600 / 60)
+        };
+        Cow::Owned(format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}{}",
+                           self.year, self.month, self.day, self.hour, self.minute, self.second, frac, offset))
+    }
+
+    fn to_rfc2822<'a>(&self) -> Cow<'a, str> {
+        let sign = if self.offset_seconds < 0 { '-' } else { '+' };
+        let abs = self.offset_seconds.abs();
+        Cow::Owned(format!("{}, {:02} {} {:04} {:02}:{:02}:{:02} {}{:02}{:02}",
+                           DAY_OF_WEEK_NAMES[self.day_of_week() as usize], self.day, MONTH_NAMES[self.month as usize - 1],
+                           self.year, self.hour, self.minute, self.second, sign, abs / 3600, abs % 3600 / 60))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::time::calendar::Calendar;
+    use crate::time::calendar::gregorian::{civil_from_days, days_from_civil, GregorianDateTime, SECONDS_PER_DAY};
+
+    #[test]
+    fn test_days_from_civil() {
+        assert_eq!(0, days_from_civil(1970, 1, 1));
+        assert_eq!(19_353, days_from_civil(2022, 12, 27));
+        assert_eq!(-1, days_from_civil(1969, 12, 31));
+        assert_eq!(11_016, days_from_civil(2000, 2, 29));
+
+        for days in -800_000..800_000 {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days, days_from_civil(y, m, d));
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(GregorianDateTime::new(2022, 12, 27, 9, 30, 0, 9 * 3600).is_some());
+        assert!(GregorianDateTime::new(2024, 2, 29, 0, 0, 0, 0).is_some());
+        assert!(GregorianDateTime::new(2023, 2, 29, 0, 0, 0, 0).is_none());
+        assert!(GregorianDateTime::new(2022, 13, 1, 0, 0, 0, 0).is_none());
+        assert!(GregorianDateTime::new(2022, 12, 27, 24, 0, 0, 0).is_none());
+        assert!(GregorianDateTime::new(2022, 12, 27, 0, 0, 0, 86_400).is_none());
+        assert!(GregorianDateTime::new(100_000_000_001, 1, 1, 0, 0, 0, 0).is_none());
+        assert!(GregorianDateTime::new(i64::MIN, 1, 1, 0, 0, 0, 0).is_none());
+
+        let t = GregorianDateTime::new(100_000_000_000, 12, 31, 23, 59, 59, -(SECONDS_PER_DAY - 1)).unwrap();
+        assert_eq!(t, GregorianDateTime::from_epoch_seconds(t.to_epoch_seconds(), 0, -(SECONDS_PER_DAY - 1)).unwrap());
+        let t = GregorianDateTime::new(-100_000_000_000, 1, 1, 0, 0, 0, SECONDS_PER_DAY - 1).unwrap();
+        assert_eq!(t, GregorianDateTime::from_epoch_seconds(t.to_epoch_seconds(), 0, SECONDS_PER_DAY - 1).unwrap());
+    }
+
+    #[test]
+    fn test_from_epoch_seconds() {
+        let t = GregorianDateTime::from_epoch_seconds(1_672_101_000, 0, 9 * 3600).unwrap();
+        assert_eq!("2022-12-27T09:30:00+09:00", t.to_rfc3339_date_time_offset());
+        assert_eq!(1_672_101_000, t.to_epoch_seconds());
+
+        let t = GregorianDateTime::from_epoch_seconds(-1, 250_000_000, -(5 * 3600 + 30 * 60)).unwrap();
+        assert_eq!("1969-12-31T18:29:59.25-05:30", t.to_rfc3339_date_time_offset());
+        assert_eq!(-1, t.to_epoch_seconds());
+
+        assert_eq!(None, GregorianDateTime::from_epoch_seconds(0, 0, 86_400));
+        assert_eq!(None, GregorianDateTime::from_epoch_seconds(0, 0, -86_400));
+        assert_eq!(None, GregorianDateTime::from_epoch_seconds(0, 1_000_000_000, 0));
+        assert_eq!(None, GregorianDateTime::from_epoch_seconds(i64::MAX, 0, 1));
+        assert_eq!(None, GregorianDateTime::from_epoch_seconds(i64::MIN, 0, -1));
+        assert_eq!(None, GregorianDateTime::from_epoch_seconds(i64::MAX, 0, 0));
+    }
+
+    #[test]
+    fn test_to_epoch_seconds() {
+        assert_eq!(0, GregorianDateTime::new(1970, 1, 1, 9, 0, 0, 9 * 3600).unwrap().to_epoch_seconds());
+        assert_eq!(0, GregorianDateTime::new(1969, 12, 31, 19, 0, 0, -5 * 3600).unwrap().to_epoch_seconds());
+
+        let offsets = [0, 9 * 3600, -(5 * 3600 + 30 * 60), 14 * 3600, -12 * 3600];
+        for secs in (-20_000_000_000i64..20_000_000_000).step_by(86_399 * 37) {
+            for offset in offsets {
+                let t = GregorianDateTime::from_epoch_seconds(secs, 0, offset).unwrap();
+                assert_eq!(secs, t.to_epoch_seconds());
+                assert_eq!(Some(t), GregorianDateTime::from_epoch_seconds(t.to_epoch_seconds(), 0, offset));
+            }
+        }
+    }
+
+    #[test]
+    fn test_day_of_week() {
+        assert_eq!(4, GregorianDateTime::new(1970, 1, 1, 0, 0, 0, 0).unwrap().day_of_week());
+        assert_eq!(2, GregorianDateTime::new(2022, 12, 27, 9, 30, 0, 9 * 3600).unwrap().day_of_week());
+        assert_eq!(0, GregorianDateTime::new(2023, 1, 1, 0, 0, 0, 0).unwrap().day_of_week());
+        assert_eq!(3, GregorianDateTime::new(1969, 12, 31, 0, 0, 0, 0).unwrap().day_of_week());
+    }
+
+    #[test]
+    fn test_to_rfc2822() {
+        let t = GregorianDateTime::new(2022, 12, 27, 9, 30, 0, 9 * 3600).unwrap();
+        assert_eq!("Tue, 27 Dec 2022 09:30:00 +0900", t.to_rfc2822());
+
+        let t = GregorianDateTime::new(2024, 2, 29, 23, 59, 59, 0).unwrap();
+        assert_eq!("Thu, 29 Feb 2024 23:59:59 +0000", t.to_rfc2822());
+
+        let t = GregorianDateTime::new(2023, 7, 1, 0, 0, 0, -(5 * 3600 + 30 * 60)).unwrap();
+        assert_eq!("Sat, 01 Jul 2023 00:00:00 -0530", t.to_rfc2822());
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt;
+use std::fmt::Formatter;
+use std::time::Duration as StdDuration;
+
+/// Span of time, non-negative with nanosecond precision.
+/// This implementation is the wrapper of [`std::time::Duration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration {
+    d: StdDuration,
+}
+
+impl Duration {
+    /// Create new instance from the [`std::time::Duration`] instance.
+    pub fn new(d: StdDuration) -> Self { Self { d } }
+
+    /// Zero length duration.
+    pub fn zero() -> Self {
+        Self::new(StdDuration::ZERO)
+    }
+
+    /// Create duration from the number of whole seconds.
+    pub fn from_secs(secs: u64) -> Self {
+        Self::new(StdDuration::from_secs(secs))
+    }
+
+    /// Create duration from the number of milliseconds.
+    pub fn from_millis(millis: u64) -> Self {
+        Self::new(StdDuration::from_millis(millis))
+    }
+
+    /// Create duration from the number of nanoseconds.
+    pub fn from_nanos(nanos: u64) -> Self {
+        Self::new(StdDuration::from_nanos(nanos))
+    }
+
+    /// Returns the number of whole seconds.
+    pub fn as_secs(&self) -> u64 {
+        self.d.as_secs()
+    }
+
+    /// Returns the total number of whole milliseconds.
+    pub fn as_millis(&self) -> u128 {
+        self.d.as_millis()
+    }
+
+    /// Returns the total number of nanoseconds.
+    pub fn as_nanos(&self) -> u128 {
+        self.d.as_nanos()
+    }
+
+    /// Returns the fractional part of the duration in nanoseconds (less than one second).
+    pub fn subsec_nanos(&self) -> u32 {
+        self.d.subsec_nanos()
+    }
+
+    /// Returns true if the duration is zero length.
+    pub fn is_zero(&self) -> bool {
+        self.d.is_zero()
+    }
+
+    /// Returns `self - other`, or `None` if the result would be negative.
+    pub fn checked_sub(&self, other: &Duration) -> Option<Duration> {
+        self.d.checked_sub(other.d).map(Self::new)
+    }
+
+    /// Returns `self - other`, or zero duration if the result would be negative.
+    pub fn saturating_sub(&self, other: &Duration) -> Duration {
+        Self::new(self.d.saturating_sub(other.d))
+    }
+
+    /// Returns human-readable form like `1h 23m 45s` or `250ms`.
+    /// Zero components are omitted, and the fraction less than a millisecond is truncated.
+    /// Returns `0s` if the duration is shorter than a millisecond.
+    pub fn humanize(&self) -> String {
+        let secs = self.as_secs();
+        let parts: Vec<String> = [
+            (secs / 3600, "h"),
+            (secs % 3600 / 60, "m"),
+            (secs % 60, "s"),
+            ((self.subsec_nanos() / 1_000_000) as u64, "ms"),
+        ]
+            .iter()
+            .filter(|(n, _)| *n != 0)
+            .map(|(n, unit)| format!("{}{}", n, unit))
+            .collect();
+
+        if parts.is_empty() {
+            "0s".to_string()
+        } else {
+            parts.join(" ")
+        }
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.humanize())
+    }
+}
+
+impl From<StdDuration> for Duration {
+    fn from(d: StdDuration) -> Self {
+        Self::new(d)
+    }
+}
+
+impl From<Duration> for StdDuration {
+    fn from(d: Duration) -> Self {
+        d.d
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration as StdDuration;
+    use crate::time::duration::Duration;
+
+    #[test]
+    fn test_duration() {
+        let d = Duration::from_millis(1_500);
+        assert_eq!(1, d.as_secs());
+        assert_eq!(1_500, d.as_millis());
+        assert_eq!(1_500_000_000, d.as_nanos());
+        assert_eq!(500_000_000, d.subsec_nanos());
+        assert!(!d.is_zero());
+
+        assert!(Duration::zero().is_zero());
+        assert_eq!(Duration::from_secs(90), Duration::from_nanos(90_000_000_000));
+        assert!(Duration::from_secs(1) < Duration::from_millis(1_001));
+        assert_eq!(StdDuration::from_millis(1_500), StdDuration::from(d));
+    }
+
+    #[test]
+    fn test_sub() {
+        let a = Duration::from_millis(1_500);
+        let b = Duration::from_secs(1);
+
+        assert_eq!(Some(Duration::from_millis(500)), a.checked_sub(&b));
+        assert_eq!(Some(Duration::zero()), a.checked_sub(&a));
+        assert_eq!(None, b.checked_sub(&a));
+
+        assert_eq!(Duration::from_millis(500), a.saturating_sub(&b));
+        assert_eq!(Duration::zero(), b.saturating_sub(&a));
+    }
+
+    #[test]
+    fn test_humanize() {
+        assert_eq!("1h 1m 1s", Duration::from_secs(3_661).humanize());
+        assert_eq!("250ms", Duration::from_millis(250).humanize());
+        assert_eq!("1h 23m 45s", Duration::from_secs(5_025).humanize());
+        assert_eq!("2h 5s", Duration::from_secs(7_205).humanize());
+        assert_eq!("1s 500ms", Duration::from_millis(1_500).humanize());
+        assert_eq!("25h", Duration::from_secs(90_000).humanize());
+        assert_eq!("0s", Duration::zero().humanize());
+        assert_eq!("0s", Duration::from_nanos(999_999).humanize());
+        assert_eq!("1m 1ms", Duration::from_nanos(60_001_999_999).to_string());
+    }
+}
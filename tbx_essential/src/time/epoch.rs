@@ -0,0 +1,61 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::time::duration::Duration;
+use crate::time::error::TimeError;
+
+/// Time relative to the Unix epoch (1970-01-01T00:00:00Z).
+pub trait Epoch {
+    /// Returns the duration since the Unix epoch.
+    /// Returns [`TimeError::BeforeEpoch`] if the time is before the epoch.
+    fn since_epoch(&self) -> Result<Duration, TimeError>;
+
+    /// Returns whole seconds since the Unix epoch.
+    /// Saturates to `0` if the time is before the epoch.
+    fn epoch_second(&self) -> u64 {
+        self.since_epoch().map_or(0, |d| d.as_secs())
+    }
+
+    /// Returns whole milliseconds since the Unix epoch.
+    /// Saturates to `0` if the time is before the epoch.
+    fn epoch_millis(&self) -> u128 {
+        self.since_epoch().map_or(0, |d| d.as_millis())
+    }
+}
+
+impl Epoch for SystemTime {
+    fn since_epoch(&self) -> Result<Duration, TimeError> {
+        self.duration_since(UNIX_EPOCH)
+            .map(Duration::from)
+            .map_err(|_| TimeError::BeforeEpoch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration as StdDuration, SystemTime, UNIX_EPOCH};
+    use crate::time::epoch::Epoch;
+    use crate::time::error::TimeError;
+
+    #[test]
+    fn test_system_time() {
+        assert_eq!(0, UNIX_EPOCH.epoch_second());
+        assert_eq!(0, UNIX_EPOCH.epoch_millis());
+
+        // 2022-02-22T19:22:22Z .. 2100-01-01T00:00:00Z
+        let now = SystemTime::now().epoch_millis();
+        assert!(1_645_557_742_000 < now && now < 4_102_444_800_000);
+
+        let t = UNIX_EPOCH + StdDuration::from_millis(1_645_557_742_250);
+        assert_eq!(1_645_557_742, t.epoch_second());
+        assert_eq!(1_645_557_742_250, t.epoch_millis());
+    }
+
+    #[test]
+    fn test_before_epoch() {
+        let t = UNIX_EPOCH - StdDuration::from_secs(1);
+        assert_eq!(Err(TimeError::BeforeEpoch), t.since_epoch());
+        assert_eq!("time is before the Unix epoch", t.since_epoch().unwrap_err().to_string());
+        assert_eq!(0, t.epoch_second());
+        assert_eq!(0, t.epoch_millis());
+    }
+}
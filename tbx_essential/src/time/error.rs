@@ -0,0 +1,44 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+
+/// Error of the time and date operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeError {
+    /// The time is before the Unix epoch (1970-01-01T00:00:00Z), such as the system clock set to the past.
+    BeforeEpoch,
+
+    /// The result of the operation is out of the representable range.
+    Overflow,
+
+    /// The text is not the expected date or time format. Holds the text.
+    InvalidFormat(String),
+}
+
+impl fmt::Display for TimeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TimeError::BeforeEpoch => write!(f, "time is before the Unix epoch"),
+            TimeError::Overflow => write!(f, "time overflow"),
+            TimeError::InvalidFormat(text) => write!(f, "invalid date or time format: {}", text),
+        }
+    }
+}
+
+impl Error for TimeError {}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use crate::time::error::TimeError;
+
+    #[test]
+    fn test_error() {
+        let e: Box<dyn Error> = Box::new(TimeError::BeforeEpoch);
+        assert_eq!("time is before the Unix epoch", e.to_string());
+        let e: Box<dyn Error> = Box::new(TimeError::Overflow);
+        assert_eq!("time overflow", e.to_string());
+        let e: Box<dyn Error> = Box::new(TimeError::InvalidFormat("2022-13-01".to_string()));
+        assert_eq!("invalid date or time format: 2022-13-01", e.to_string());
+    }
+}
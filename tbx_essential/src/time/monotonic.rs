@@ -0,0 +1,62 @@
+use std::time::Instant as StdInstant;
+
+use crate::time::duration::Duration;
+
+/// Measurement of a monotonically non-decreasing clock, for measuring elapsed time.
+/// This implementation is the wrapper of [`std::time::Instant`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant {
+    i: StdInstant,
+}
+
+impl Instant {
+    /// Returns an instant corresponding to "now".
+    pub fn now() -> Self {
+        Self { i: StdInstant::now() }
+    }
+
+    /// Returns the amount of time elapsed since this instant was created.
+    pub fn elapsed(&self) -> Duration {
+        Duration::new(self.i.elapsed())
+    }
+
+    /// Returns the amount of time elapsed from `earlier` to this instant.
+    /// Returns zero duration if `earlier` is later than this instant.
+    pub fn duration_since(&self, earlier: &Instant) -> Duration {
+        Duration::new(self.i.saturating_duration_since(earlier.i))
+    }
+
+    /// Returns the amount of time elapsed from `earlier` to this instant,
+    /// or `None` if `earlier` is later than this instant.
+    pub fn checked_duration_since(&self, earlier: &Instant) -> Option<Duration> {
+        self.i.checked_duration_since(earlier.i).map(Duration::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::time::monotonic::Instant;
+
+    #[test]
+    fn test_elapsed() {
+        let a = Instant::now();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let b = Instant::now();
+
+        assert!(a < b);
+        assert!(2 <= a.elapsed().as_millis());
+        assert!(2 <= b.duration_since(&a).as_millis());
+        assert!(a.duration_since(&b).is_zero());
+    }
+
+    #[test]
+    fn test_checked_duration_since() {
+        let a = Instant::now();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let b = Instant::now();
+
+        assert!(2 <= b.checked_duration_since(&a).unwrap().as_millis());
+        assert!(a.checked_duration_since(&a).unwrap().is_zero());
+        assert_eq!(None, a.checked_duration_since(&b));
+    }
+}
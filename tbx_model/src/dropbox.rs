@@ -1 +1 @@
-pub mod stone;
\ No newline at end of file
+pub mod stone;
@@ -1,3 +1,9 @@
+pub mod ast;
+pub mod codegen;
+pub mod error;
+pub mod jsonschema;
+pub mod validate;
+
 //use pest::Parser;
 use pest_derive::Parser;
 
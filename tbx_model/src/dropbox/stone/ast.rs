@@ -0,0 +1,838 @@
+use std::collections::HashMap;
+use pest::iterators::{Pair, Pairs};
+use pest::Parser;
+use crate::dropbox::stone::{Rule, StoneParser};
+use crate::dropbox::stone::error::AstError;
+
+/// Namespace, the root element of the Stone spec file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Namespace {
+    pub name: String,
+    pub doc: Option<String>,
+
+    /// Imported namespaces like `common`.
+    pub imports: Vec<String>,
+    pub aliases: Vec<AliasDef>,
+    pub structs: Vec<StructDef>,
+    pub unions: Vec<UnionDef>,
+    pub routes: Vec<RouteDef>,
+}
+
+/// Data type reference like `String`, `List(Photo)` or `common.Photo?`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeRef {
+    /// Namespace of the qualified reference like `common` of `common.Photo`.
+    /// `None` for the local reference or the primitive type.
+    pub namespace: Option<String>,
+
+    /// Type name without namespace like `String`, `List` or `Photo`.
+    pub name: String,
+
+    /// Item type of `List`.
+    pub item: Option<Box<TypeRef>>,
+
+    /// True if the type is declared with `?`.
+    pub optional: bool,
+}
+
+/// Alias definition like `alias AccountId = String(min_length=40, max_length=40)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AliasDef {
+    pub name: String,
+    pub type_ref: TypeRef,
+    pub doc: Option<String>,
+}
+
+/// Struct definition like `struct GetAccountArg`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructDef {
+    pub name: String,
+    pub extends: Option<TypeRef>,
+    pub doc: Option<String>,
+    pub fields: Vec<StructField>,
+}
+
+/// Field of the struct like `account_id users_common.AccountId`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructField {
+    pub name: String,
+    pub type_ref: TypeRef,
+    pub doc: Option<String>,
+}
+
+/// Union definition like `union GetAccountError`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnionDef {
+    pub name: String,
+    pub extends: Option<TypeRef>,
+    pub doc: Option<String>,
+
+    /// True if the union is declared with `union_closed`.
+    pub closed: bool,
+
+    /// Tags of the union in the declared order.
+    pub tags: Vec<UnionTag>,
+}
+
+/// Tag of the union like `complete RelocationBatchResult` or `other`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnionTag {
+    pub name: String,
+
+    /// Type of the tag. `None` for the void tag like `other`.
+    pub type_ref: Option<TypeRef>,
+    pub doc: Option<String>,
+
+    /// True if the void tag is the catch-all tag marked with `*` like `other*`.
+    pub catch_all: bool,
+}
+
+/// Route definition like `route get_account(GetAccountArg, BasicAccount, GetAccountError)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteDef {
+    /// Route name with path and version like `devices/revoke_device_session_batch`.
+    pub name: String,
+    pub arg: TypeRef,
+    pub result: TypeRef,
+    pub error: TypeRef,
+    pub doc: Option<String>,
+
+    /// Route attributes like `auth = "team"`.
+    /// Values are normalized to strings (e.g. `"team"` -> `team`, `true` -> `true`).
+    pub attrs: HashMap<String, String>,
+}
+
+fn first_pair(mut pairs: Pairs<Rule>, rule: Rule) -> Result<Pair<Rule>, AstError> {
+    match pairs.next() {
+        Some(p) => Ok(p),
+        None => Err(AstError::MissingElement(rule)),
+    }
+}
+
+/// Returns the content of the literal string without quotes. Escape sequences are kept as it is.
+fn string_content(pair: Pair<Rule>) -> String {
+    match pair.into_inner().flatten().find(|p| p.as_rule() == Rule::literal_string_inner) {
+        Some(p) => p.as_str().to_string(),
+        None => String::new(),
+    }
+}
+
+/// Normalize `literal` to string.
+fn literal_value(pair: Pair<Rule>) -> String {
+    let text = pair.as_str().to_string();
+    match pair.into_inner().next() {
+        Some(p) if p.as_rule() == Rule::literal_string => string_content(p),
+        _ => text,
+    }
+}
+
+/// Unescape the string literal like `"Hello\nWorld"`. Surrounding quotes are removed if exist.
+/// Supported escape sequences are `\n`, `\r`, `\t`, `\"`, `\\` and `\uXXXX`.
+pub fn unescape_stone_string(raw: &str) -> Result<String, AstError> {
+    let inner = match raw.strip_prefix('"').and_then(|r| r.strip_suffix('"')) {
+        Some(i) => i,
+        None => raw,
+    };
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('t') => out.push('\t'),
+            Some('"') => out.push('"'),
+            Some('\\') => out.push('\\'),
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                // from_str_radix accepts a sign like `+041`, then check digits first
+                let digits = hex.len() == 4 && hex.chars().all(|c| c.is_ascii_hexdigit());
+                match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                    Some(u) if digits => out.push(u),
+                    _ => return Err(AstError::InvalidEscape(format!("\\u{}", hex))),
+                }
+            }
+            Some(e) => return Err(AstError::InvalidEscape(format!("\\{}", e))),
+            None => return Err(AstError::InvalidEscape("\\".to_string())),
+        }
+    }
+    Ok(out)
+}
+
+/// Escape the string as the string literal with surrounding quotes. Inverse of [`unescape_stone_string`].
+pub fn escape_stone_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Returns the doc string line at the indent level (4 spaces per level), or empty string if no doc.
+/// The doc string is kept as it is written in the source, then no escaping is applied.
+fn doc_line(doc: &Option<String>, level: usize) -> String {
+    match doc {
+        Some(d) => format!("{}\"{}\"\n", "    ".repeat(level), d),
+        None => String::new(),
+    }
+}
+
+/// Returns the literal of the normalized route attribute value.
+fn attr_literal(value: &str) -> String {
+    if value == "true" || value == "false" || value == "null" || value.parse::<f64>().is_ok() {
+        value.to_string()
+    } else {
+        format!("\"{}\"", value)
+    }
+}
+
+impl Namespace {
+    /// Parse the Stone spec source.
+    pub fn parse(source: &str) -> Result<Namespace, AstError> {
+        let spec = first_pair(StoneParser::parse(Rule::spec, source)?, Rule::spec)?;
+        Self::from_pair(spec)
+    }
+
+    fn from_pair(pair: Pair<Rule>) -> Result<Namespace, AstError> {
+        let mut name: Option<String> = None;
+        let mut doc: Option<String> = None;
+        let mut imports: Vec<String> = Vec::new();
+        let mut aliases: Vec<AliasDef> = Vec::new();
+        let mut structs: Vec<StructDef> = Vec::new();
+        let mut unions: Vec<UnionDef> = Vec::new();
+        let mut routes: Vec<RouteDef> = Vec::new();
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::spec_namespace => {
+                    for q in p.into_inner() {
+                        match q.as_rule() {
+                            Rule::identity => name = Some(q.as_str().to_string()),
+                            Rule::spec_doc => doc = Some(string_content(q)),
+                            _ => {}
+                        }
+                    }
+                }
+                Rule::spec_definition => {
+                    for q in p.into_inner() {
+                        match q.as_rule() {
+                            Rule::spec_import => imports.push(first_pair(q.into_inner(), Rule::identity)?.as_str().to_string()),
+                            Rule::spec_alias => aliases.push(AliasDef::from_pair(q)?),
+                            Rule::spec_struct => structs.push(StructDef::from_pair(q)?),
+                            Rule::spec_union => unions.push(UnionDef::from_pair(q)?),
+                            Rule::spec_route => routes.push(RouteDef::from_pair(q)?),
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        match name {
+            Some(n) => Ok(Namespace { name: n, doc, imports, aliases, structs, unions, routes }),
+            None => Err(AstError::MissingElement(Rule::spec_namespace)),
+        }
+    }
+
+    /// Returns the Stone spec source of the namespace.
+    /// Definitions are emitted in the order of imports, aliases, structs, unions and routes,
+    /// separated by a blank line, with 4 spaces indentation.
+    /// Type constraints (e.g. `min_length`), examples and comments are not preserved.
+    pub fn to_stone(&self) -> String {
+        let mut blocks: Vec<String> = Vec::new();
+        if !self.imports.is_empty() {
+            blocks.push(self.imports.iter().map(|i| format!("import {}\n", i)).collect());
+        }
+        blocks.extend(self.aliases.iter().map(|a| a.to_stone()));
+        blocks.extend(self.structs.iter().map(|s| s.to_stone()));
+        blocks.extend(self.unions.iter().map(|u| u.to_stone()));
+        blocks.extend(self.routes.iter().map(|r| r.to_stone()));
+
+        format!("namespace {}\n{}\n{}", self.name, doc_line(&self.doc, 1), blocks.join("\n"))
+    }
+}
+
+impl TypeRef {
+    /// Parse type like `List(String)?`.
+    pub fn parse(source: &str) -> Result<TypeRef, AstError> {
+        let t = first_pair(StoneParser::parse(Rule::type_all_optional, source)?, Rule::type_all_optional)?;
+        Self::from_pair(t)
+    }
+
+    /// Build from `type_all_optional` or `type_all`.
+    fn from_pair(pair: Pair<Rule>) -> Result<TypeRef, AstError> {
+        match pair.as_rule() {
+            Rule::type_all_optional => {
+                let optional = pair.as_str().trim_end().ends_with('?');
+                let t = first_pair(pair.into_inner(), Rule::type_all)?;
+                Ok(TypeRef { optional, ..Self::from_pair(t)? })
+            }
+            _ => {
+                let t = first_pair(pair.into_inner(), Rule::type_all)?;
+                match t.as_rule() {
+                    Rule::type_list => {
+                        let item = first_pair(t.into_inner(), Rule::type_all_optional)?;
+                        Ok(TypeRef {
+                            namespace: None,
+                            name: "List".to_string(),
+                            item: Some(Box::new(Self::from_pair(item)?)),
+                            optional: false,
+                        })
+                    }
+                    // drop constraints like `String(min_length=1)`
+                    Rule::type_primitive => Ok(TypeRef {
+                        namespace: None,
+                        name: t.as_str().chars().take_while(|c| c.is_ascii_alphanumeric()).collect(),
+                        item: None,
+                        optional: false,
+                    }),
+                    _ => Ok(Self::from_identity_ref(t)),
+                }
+            }
+        }
+    }
+
+    /// Build from `identity_ref` like `common.Photo` or `Photo`.
+    fn from_identity_ref(pair: Pair<Rule>) -> TypeRef {
+        let (namespace, name) = match pair.as_str().split_once('.') {
+            Some((ns, name)) => (Some(ns.to_string()), name.to_string()),
+            None => (None, pair.as_str().to_string()),
+        };
+        TypeRef { namespace, name, item: None, optional: false }
+    }
+
+    /// Returns the type in the Stone spec syntax like `List(common.Photo)?`.
+    pub fn to_stone(&self) -> String {
+        let name = match (&self.namespace, &self.item) {
+            (_, Some(item)) => format!("{}({})", self.name, item.to_stone()),
+            (Some(ns), None) => format!("{}.{}", ns, self.name),
+            (None, None) => self.name.clone(),
+        };
+        if self.optional { name + "?" } else { name }
+    }
+}
+
+impl AliasDef {
+    fn from_pair(pair: Pair<Rule>) -> Result<AliasDef, AstError> {
+        let mut name: Option<String> = None;
+        let mut type_ref: Option<TypeRef> = None;
+        let mut doc: Option<String> = None;
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::identity => name = Some(p.as_str().to_string()),
+                Rule::type_all_optional => type_ref = Some(TypeRef::from_pair(p)?),
+                Rule::spec_doc => doc = Some(string_content(p)),
+                _ => {}
+            }
+        }
+
+        match (name, type_ref) {
+            (Some(n), Some(t)) => Ok(AliasDef { name: n, type_ref: t, doc }),
+            (None, _) => Err(AstError::MissingElement(Rule::identity)),
+            _ => Err(AstError::MissingElement(Rule::type_all_optional)),
+        }
+    }
+
+    /// Returns the Stone spec source of the alias.
+    pub fn to_stone(&self) -> String {
+        format!("alias {} = {}\n{}", self.name, self.type_ref.to_stone(), doc_line(&self.doc, 1))
+    }
+}
+
+impl StructDef {
+    /// Parse single struct definition.
+    pub fn parse(source: &str) -> Result<StructDef, AstError> {
+        let s = first_pair(StoneParser::parse(Rule::spec_struct, source)?, Rule::spec_struct)?;
+        Self::from_pair(s)
+    }
+
+    fn from_pair(pair: Pair<Rule>) -> Result<StructDef, AstError> {
+        let mut name: Option<String> = None;
+        let mut extends: Option<TypeRef> = None;
+        let mut doc: Option<String> = None;
+        let mut fields: Vec<StructField> = Vec::new();
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::identity => name = Some(p.as_str().to_string()),
+                Rule::spec_struct_extends => extends = Some(TypeRef::from_identity_ref(first_pair(p.into_inner(), Rule::identity_ref)?)),
+                Rule::spec_doc => doc = Some(string_content(p)),
+                Rule::spec_struct_field => fields.push(StructField::from_pair(p)?),
+                _ => {}
+            }
+        }
+
+        match name {
+            Some(n) => Ok(StructDef { name: n, extends, doc, fields }),
+            None => Err(AstError::MissingElement(Rule::identity)),
+        }
+    }
+
+    /// Returns the Stone spec source of the struct.
+    pub fn to_stone(&self) -> String {
+        let mut out = format!("struct {}", self.name);
+        if let Some(e) = &self.extends {
+            out.push_str(&format!(" extends {}", e.to_stone()));
+        }
+        out.push('\n');
+        out.push_str(&doc_line(&self.doc, 1));
+        if !self.fields.is_empty() {
+            out.push('\n');
+        }
+        for f in &self.fields {
+            out.push_str(&format!("    {} {}\n{}", f.name, f.type_ref.to_stone(), doc_line(&f.doc, 2)));
+        }
+        out
+    }
+}
+
+impl StructField {
+    fn from_pair(pair: Pair<Rule>) -> Result<StructField, AstError> {
+        let mut name: Option<String> = None;
+        let mut type_ref: Option<TypeRef> = None;
+        let mut doc: Option<String> = None;
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::identity => name = Some(p.as_str().to_string()),
+                Rule::type_all_optional => type_ref = Some(TypeRef::from_pair(p)?),
+                Rule::spec_doc => doc = Some(string_content(p)),
+                _ => {}
+            }
+        }
+
+        match (name, type_ref) {
+            (Some(n), Some(t)) => Ok(StructField { name: n, type_ref: t, doc }),
+            (None, _) => Err(AstError::MissingElement(Rule::identity)),
+            _ => Err(AstError::MissingElement(Rule::type_all_optional)),
+        }
+    }
+}
+
+impl UnionDef {
+    /// Parse single union definition.
+    pub fn parse(source: &str) -> Result<UnionDef, AstError> {
+        let u = first_pair(StoneParser::parse(Rule::spec_union, source)?, Rule::spec_union)?;
+        Self::from_pair(u)
+    }
+
+    fn from_pair(pair: Pair<Rule>) -> Result<UnionDef, AstError> {
+        let closed = pair.as_str().starts_with("union_closed");
+        let mut name: Option<String> = None;
+        let mut extends: Option<TypeRef> = None;
+        let mut doc: Option<String> = None;
+        let mut tags: Vec<UnionTag> = Vec::new();
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::identity => name = Some(p.as_str().to_string()),
+                Rule::spec_union_extends => extends = Some(TypeRef::from_identity_ref(first_pair(p.into_inner(), Rule::identity_ref)?)),
+                Rule::spec_doc => doc = Some(string_content(p)),
+                Rule::spec_union_tag | Rule::spec_union_void_tag => tags.push(UnionTag::from_pair(p)?),
+                _ => {}
+            }
+        }
+
+        match name {
+            Some(n) => Ok(UnionDef { name: n, extends, doc, closed, tags }),
+            None => Err(AstError::MissingElement(Rule::identity)),
+        }
+    }
+
+    /// Returns tags of the union in the declared order.
+    pub fn tags(&self) -> &[UnionTag] {
+        &self.tags
+    }
+
+    /// Returns the Stone spec source of the union.
+    pub fn to_stone(&self) -> String {
+        let mut out = format!("{} {}", if self.closed { "union_closed" } else { "union" }, self.name);
+        if let Some(e) = &self.extends {
+            out.push_str(&format!(" extends {}", e.to_stone()));
+        }
+        out.push('\n');
+        out.push_str(&doc_line(&self.doc, 1));
+        if !self.tags.is_empty() {
+            out.push('\n');
+        }
+        for t in &self.tags {
+            match &t.type_ref {
+                Some(type_ref) => out.push_str(&format!("    {} {}\n", t.name, type_ref.to_stone())),
+                None => out.push_str(&format!("    {}{}\n", t.name, if t.catch_all { "*" } else { "" })),
+            }
+            out.push_str(&doc_line(&t.doc, 2));
+        }
+        out
+    }
+}
+
+impl UnionTag {
+    /// Build from `spec_union_tag` or `spec_union_void_tag`.
+    fn from_pair(pair: Pair<Rule>) -> Result<UnionTag, AstError> {
+        let mut name: Option<String> = None;
+        let mut type_ref: Option<TypeRef> = None;
+        let mut doc: Option<String> = None;
+        let mut catch_all = false;
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                Rule::identity => name = Some(p.as_str().to_string()),
+                Rule::spec_union_catch_all => catch_all = true,
+                Rule::type_all_optional => type_ref = Some(TypeRef::from_pair(p)?),
+                Rule::spec_doc => doc = Some(string_content(p)),
+                _ => {}
+            }
+        }
+
+        match name {
+            Some(n) => Ok(UnionTag { name: n, type_ref, doc, catch_all }),
+            None => Err(AstError::MissingElement(Rule::identity)),
+        }
+    }
+}
+
+impl RouteDef {
+    /// Parse single route definition.
+    pub fn parse(source: &str) -> Result<RouteDef, AstError> {
+        let route = first_pair(StoneParser::parse(Rule::spec_route, source)?, Rule::spec_route)?;
+        Self::from_pair(route)
+    }
+
+    fn from_pair(pair: Pair<Rule>) -> Result<RouteDef, AstError> {
+        let mut name: Option<String> = None;
+        let mut types: Vec<TypeRef> = Vec::new();
+        let mut doc: Option<String> = None;
+        let mut attrs: HashMap<String, String> = HashMap::new();
+
+        for p in pair.into_inner() {
+            match p.as_rule() {
+                // second `identity_route` is the route of `deprecated by`
+                Rule::identity_route if name.is_none() => name = Some(p.as_str().to_string()),
+                Rule::type_all => types.push(TypeRef::from_pair(p)?),
+                Rule::spec_doc => doc = Some(string_content(p)),
+                Rule::spec_route_attrs => {
+                    for attr in p.into_inner() {
+                        let mut kv = attr.into_inner();
+                        match (kv.next(), kv.next()) {
+                            (Some(k), Some(v)) => {
+                                attrs.insert(k.as_str().to_string(), literal_value(v));
+                            }
+                            _ => return Err(AstError::MissingElement(Rule::spec_route_attr)),
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut types = types.into_iter();
+        match (name, types.next(), types.next(), types.next()) {
+            (Some(n), Some(arg), Some(result), Some(error)) => Ok(RouteDef { name: n, arg, result, error, doc, attrs }),
+            (None, _, _, _) => Err(AstError::MissingElement(Rule::identity_route)),
+            _ => Err(AstError::MissingElement(Rule::type_all)),
+        }
+    }
+
+    /// Returns the Stone spec source of the route. Attributes are sorted by the key.
+    pub fn to_stone(&self) -> String {
+        let mut out = format!("route {}({}, {}, {})\n{}",
+                              self.name, self.arg.to_stone(), self.result.to_stone(), self.error.to_stone(), doc_line(&self.doc, 1));
+        if !self.attrs.is_empty() {
+            let mut keys: Vec<&String> = self.attrs.keys().collect();
+            keys.sort();
+            out.push_str("\n    attrs\n");
+            for k in keys {
+                out.push_str(&format!("        {} = {}\n", k, attr_literal(&self.attrs[k])));
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dropbox::stone::ast::{escape_stone_string, unescape_stone_string, Namespace, RouteDef, StructDef, TypeRef, UnionDef, UnionTag};
+    use crate::dropbox::stone::error::AstError;
+
+    #[test]
+    fn test_unescape_stone_string() {
+        assert_eq!("Hello\nWorld", unescape_stone_string("\"Hello\\nWorld\"").unwrap());
+        assert_eq!("Hello\r\nWorld", unescape_stone_string("Hello\\r\\nWorld").unwrap());
+        assert_eq!("say \"hi\"\t\\", unescape_stone_string(r#""say \"hi\"\t\\""#).unwrap());
+        assert_eq!("é", unescape_stone_string(r#""\u00e9""#).unwrap());
+
+        assert!(matches!(unescape_stone_string(r#""\x""#), Err(AstError::InvalidEscape(_))));
+        assert!(matches!(unescape_stone_string(r#""\u00""#), Err(AstError::InvalidEscape(_))));
+        assert!(matches!(unescape_stone_string(r#""\u+041""#), Err(AstError::InvalidEscape(_))));
+        assert!(matches!(unescape_stone_string(r#""\u-041""#), Err(AstError::InvalidEscape(_))));
+        assert!(matches!(unescape_stone_string("abc\\"), Err(AstError::InvalidEscape(_))));
+    }
+
+    #[test]
+    fn test_escape_stone_string() {
+        assert_eq!(r#""Hello\nWorld""#, escape_stone_string("Hello\nWorld"));
+        assert_eq!(r#""\u0000""#, escape_stone_string("\0"));
+
+        for s in ["", "say \"hi\"", "C:\\path\\to\\\"file\"", "line1\r\nline2\ttab", "日本語 \u{1}"] {
+            let escaped = escape_stone_string(s);
+            assert_eq!(s, unescape_stone_string(&escaped).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_type_ref() {
+        let t = TypeRef::parse("String").unwrap();
+        assert_eq!(t.name, "String");
+        assert!(!t.optional);
+        assert!(t.item.is_none());
+
+        let t = TypeRef::parse(r#"String(pattern="[0-9]+", max_length=10)?"#).unwrap();
+        assert_eq!(t.name, "String");
+        assert!(t.optional);
+
+        let t = TypeRef::parse("List(common.Photo?, max_items=10)?").unwrap();
+        assert_eq!(t.name, "List");
+        assert!(t.optional);
+        let item = t.item.unwrap();
+        assert_eq!(item.namespace.as_deref(), Some("common"));
+        assert_eq!(item.name, "Photo");
+        assert!(item.optional);
+        assert_eq!(t.namespace, None);
+    }
+
+    #[test]
+    fn test_type_ref_namespace() {
+        assert_eq!(TypeRef::parse("account.PhotoSourceArg").unwrap(), TypeRef {
+            namespace: Some("account".to_string()),
+            name: "PhotoSourceArg".to_string(),
+            item: None,
+            optional: false,
+        });
+        assert_eq!(TypeRef::parse("Timestamp?").unwrap(), TypeRef {
+            namespace: None,
+            name: "Timestamp".to_string(),
+            item: None,
+            optional: true,
+        });
+        assert_eq!(TypeRef::parse("PhotoSourceArg").unwrap().namespace, None);
+    }
+
+    #[test]
+    fn test_struct() {
+        let s = StructDef::parse(r#"struct GetAccountArg
+    account_id users_common.AccountId
+        "A user's account identifier."
+    include_photo Boolean?
+
+    example default
+        account_id = "dbid:AAH4f99T0taONIb-OurWxbNQ6ywGRopQngc"
+    "#).unwrap();
+
+        assert_eq!(s.name, "GetAccountArg");
+        assert_eq!(s.extends, None);
+        assert_eq!(s.fields.len(), 2);
+        assert_eq!(s.fields[0].name, "account_id");
+        assert_eq!(s.fields[0].type_ref.namespace.as_deref(), Some("users_common"));
+        assert_eq!(s.fields[0].type_ref.name, "AccountId");
+        assert_eq!(s.fields[0].doc.as_deref(), Some("A user's account identifier."));
+        assert_eq!(s.fields[1].name, "include_photo");
+        assert!(s.fields[1].type_ref.optional);
+    }
+
+    #[test]
+    fn test_union() {
+        let u = UnionDef::parse(r#"union_closed RelocationBatchLaunch extends async.LaunchResultBase
+    "Result returned by copy_batch or move_batch."
+
+    complete RelocationBatchResult
+    other
+"#).unwrap();
+
+        assert_eq!(u.name, "RelocationBatchLaunch");
+        let extends = u.extends.as_ref().unwrap();
+        assert_eq!(extends.namespace.as_deref(), Some("async"));
+        assert_eq!(extends.name, "LaunchResultBase");
+        assert_eq!(u.doc.as_deref(), Some("Result returned by copy_batch or move_batch."));
+        assert!(u.closed);
+        assert_eq!(u.tags().len(), 2);
+        assert_eq!(u.tags()[1], UnionTag { name: "other".to_string(), type_ref: None, doc: None, catch_all: false });
+    }
+
+    #[test]
+    fn test_union_tags() {
+        let u = UnionDef::parse(r#"union RelocationBatchLaunch extends async.LaunchResultBase
+
+    "Result returned by :route:`copy_batch` or :route:`move_batch` that may either launch an
+    asynchronous job or complete synchronously."
+
+    complete RelocationBatchResult
+        "The copy or move completed."
+    in_progress List(files.Metadata)?
+    other*
+
+    example complete
+        complete = default
+"#).unwrap();
+
+        let tags = u.tags();
+        assert_eq!(tags.len(), 3);
+        let complete = tags.iter().find(|t| t.name == "complete").unwrap();
+        assert_eq!(complete.type_ref.as_ref().unwrap().name, "RelocationBatchResult");
+        assert_eq!(complete.doc.as_deref(), Some("The copy or move completed."));
+        let in_progress = tags[1].type_ref.as_ref().unwrap();
+        assert_eq!(in_progress.to_stone(), "List(files.Metadata)?");
+        assert_eq!(tags[2].name, "other");
+        assert_eq!(tags[2].type_ref, None);
+        assert!(tags[2].catch_all);
+        assert!(!tags[0].catch_all);
+    }
+
+    #[test]
+    fn test_route_attrs() {
+        let r = r#"route devices/revoke_device_session_batch(RevokeDeviceSessionBatchArg, RevokeDeviceSessionBatchResult, RevokeDeviceSessionBatchError)
+    "Revoke a list of device sessions of team members."
+
+    attrs
+        auth = "team"
+        scope = "sessions.modify"
+        "#;
+
+        let route = RouteDef::parse(r).unwrap();
+        assert_eq!(route.name, "devices/revoke_device_session_batch");
+        assert_eq!(route.arg.name, "RevokeDeviceSessionBatchArg");
+        assert_eq!(route.result.name, "RevokeDeviceSessionBatchResult");
+        assert_eq!(route.error.name, "RevokeDeviceSessionBatchError");
+        assert_eq!(route.error.namespace, None);
+        assert_eq!(route.doc.unwrap(), "Revoke a list of device sessions of team members.");
+        assert_eq!(route.attrs["auth"], "team");
+        assert_eq!(route.attrs["scope"], "sessions.modify");
+    }
+
+    #[test]
+    fn test_route_attrs_literals() {
+        let r = r#"route get_metadata(GetMetadataArg, Metadata, GetMetadataError)
+    attrs
+        is_preview = true
+        select_admin_mode = "whole_team"
+        max_retry = 3
+        "#;
+
+        let route = RouteDef::parse(r).unwrap();
+        assert_eq!(route.doc, None);
+        assert_eq!(route.attrs.len(), 3);
+        assert_eq!(route.attrs["is_preview"], "true");
+        assert_eq!(route.attrs["select_admin_mode"], "whole_team");
+        assert_eq!(route.attrs["max_retry"], "3");
+    }
+
+    #[test]
+    fn test_namespace() {
+        let src = r#"namespace team
+    "Team namespace."
+
+import common
+
+alias DeviceSessionId = String
+    "Device session identifier."
+
+union RevokeDeviceSessionError
+    device_session_not_found
+    member_not_found
+
+route devices/revoke_device_session(RevokeDeviceSessionArg, Void, RevokeDeviceSessionError)
+    "Revoke a device session of a team's member."
+
+    attrs
+        auth = "team"
+        scope = "sessions.modify"
+
+route features/get_values(FeaturesGetValuesBatchArg, FeaturesGetValuesBatchResult, FeaturesGetValuesBatchError)
+"#;
+
+        let ns = Namespace::parse(src).unwrap();
+        assert_eq!(ns.name, "team");
+        assert_eq!(ns.doc.unwrap(), "Team namespace.");
+        assert_eq!(ns.imports, vec!["common"]);
+        assert_eq!(ns.aliases.len(), 1);
+        assert_eq!(ns.aliases[0].name, "DeviceSessionId");
+        assert_eq!(ns.aliases[0].type_ref.name, "String");
+        assert_eq!(ns.aliases[0].doc.as_deref(), Some("Device session identifier."));
+        assert_eq!(ns.unions.len(), 1);
+        assert_eq!(ns.unions[0].name, "RevokeDeviceSessionError");
+        assert!(!ns.unions[0].closed);
+        assert_eq!(ns.routes.len(), 2);
+        assert_eq!(ns.routes[0].name, "devices/revoke_device_session");
+        assert_eq!(ns.routes[0].result.to_stone(), "Void");
+        assert_eq!(ns.routes[0].attrs["auth"], "team");
+        assert_eq!(ns.routes[1].name, "features/get_values");
+        assert!(ns.routes[1].attrs.is_empty());
+
+        assert!(Namespace::parse("route abc(A, B, C)\n").is_err());
+    }
+
+    #[test]
+    fn test_to_stone() {
+        let src = r#"namespace files
+    "Files namespace."
+
+import async
+
+alias Rev = String(min_length=9)
+    "Revision."
+
+struct RelocationBatchArg extends RelocationBatchArgBase
+    "Arguments for copy_batch or move_batch."
+
+    allow_shared_folder Boolean?
+        "Deprecated."
+    paths List(async.Path?)
+
+union RelocationBatchLaunch extends async.LaunchResultBase
+
+    "Result returned by :route:`copy_batch` or :route:`move_batch` that may either launch an
+    asynchronous job or complete synchronously."
+
+    complete RelocationBatchResult
+    other*
+
+    example complete
+        complete = default
+
+    example async_job_id
+        async_job_id = "34g93hh34h04y384084"
+
+route copy_batch:2(RelocationBatchArg, RelocationBatchLaunch, Void)
+    "Copy multiple files or folders."
+
+    attrs
+        auth = "user"
+        is_preview = true
+        max_retry = 3
+"#;
+        let ns = Namespace::parse(src).unwrap();
+        let stone = ns.to_stone();
+        assert_eq!(ns, Namespace::parse(&stone).unwrap());
+        assert_eq!(stone, Namespace::parse(&stone).unwrap().to_stone());
+
+        assert!(stone.starts_with("namespace files\n    \"Files namespace.\"\n\nimport async\n\nalias Rev = String\n    \"Revision.\"\n"));
+        assert!(stone.contains("    allow_shared_folder Boolean?\n        \"Deprecated.\"\n    paths List(async.Path?)\n"));
+        assert!(stone.contains("union RelocationBatchLaunch extends async.LaunchResultBase\n"));
+        assert!(stone.contains("\n    complete RelocationBatchResult\n    other*\n"));
+        assert!(stone.ends_with("    attrs\n        auth = \"user\"\n        is_preview = true\n        max_retry = 3\n"));
+    }
+}
@@ -0,0 +1,156 @@
+use tbx_essential::text::pattern::ascii::Ascii;
+use crate::dropbox::stone::ast::{Namespace, StructDef, TypeRef};
+
+/// Returns Rust type name for the Stone type.
+/// Namespace qualified reference like `common.Photo` is mapped to the path `common::Photo`.
+fn rust_type(t: &TypeRef) -> String {
+    let base = match (t.name.as_str(), &t.item) {
+        ("List", Some(item)) => format!("Vec<{}>", rust_type(item)),
+        ("Bytes", _) => "Vec<u8>".to_string(),
+        ("Boolean", _) => "bool".to_string(),
+        ("Float32", _) => "f32".to_string(),
+        ("Float64", _) => "f64".to_string(),
+        ("Int32", _) => "i32".to_string(),
+        ("Int64", _) => "i64".to_string(),
+        ("UInt32", _) => "u32".to_string(),
+        ("UInt64", _) => "u64".to_string(),
+        ("String", _) => "String".to_string(),
+        ("Timestamp", _) => "String".to_string(),
+        ("Void", _) => "()".to_string(),
+        (name, _) => match &t.namespace {
+            Some(ns) => format!("{}::{}", ns, name),
+            None => name.to_string(),
+        },
+    };
+    if t.optional {
+        format!("Option<{}>", base)
+    } else {
+        base
+    }
+}
+
+/// Keywords of Rust, including reserved ones.
+const RUST_KEYWORDS: [&str; 51] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen",
+    "if", "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut",
+    "override", "priv", "pub", "ref", "return", "self", "Self", "static", "struct", "super",
+    "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual", "where",
+    "while",
+];
+
+/// Returns Rust field name in snake_case for the Stone field name.
+/// Keywords are emitted as raw identifiers like `r#type`, except ones that cannot be raw
+/// identifiers (`crate`, `self`, `Self` and `super`) that are suffixed like `self_`.
+fn rust_field_name(name: &str) -> String {
+    let snake = name.to_ascii_snake_lower().to_string();
+    match snake.as_str() {
+        "crate" | "self" | "Self" | "super" => format!("{}_", snake),
+        s if RUST_KEYWORDS.contains(&s) => format!("r#{}", snake),
+        _ => snake,
+    }
+}
+
+fn push_doc(out: &mut String, indent: &str, doc: &Option<String>) {
+    if let Some(d) = doc {
+        for line in d.lines().map(|l| l.trim()) {
+            if line.is_empty() {
+                out.push_str(&format!("{}///\n", indent));
+            } else {
+                out.push_str(&format!("{}/// {}\n", indent, line));
+            }
+        }
+    }
+}
+
+fn push_struct(out: &mut String, s: &StructDef) {
+    push_doc(out, "", &s.doc);
+    out.push_str("#[derive(Debug, Clone)]\n");
+    out.push_str(&format!("pub struct {} {{\n", s.name));
+    for f in &s.fields {
+        push_doc(out, "    ", &f.doc);
+        out.push_str(&format!("    pub {}: {},\n", rust_field_name(&f.name), rust_type(&f.type_ref)));
+    }
+    out.push_str("}\n");
+}
+
+/// Generate Rust struct definitions for structs in the namespace.
+/// Fields inherited by `extends` are not included.
+pub fn to_rust(ns: &Namespace) -> String {
+    let mut out = String::new();
+    for (i, s) in ns.structs.iter().enumerate() {
+        if 0 < i {
+            out.push('\n');
+        }
+        push_struct(&mut out, s);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dropbox::stone::ast::{Namespace, TypeRef};
+    use crate::dropbox::stone::codegen::{rust_type, to_rust};
+
+    #[test]
+    fn test_rust_type() {
+        assert_eq!(rust_type(&TypeRef::parse("String").unwrap()), "String");
+        assert_eq!(rust_type(&TypeRef::parse("Int64").unwrap()), "i64");
+        assert_eq!(rust_type(&TypeRef::parse("Boolean").unwrap()), "bool");
+        assert_eq!(rust_type(&TypeRef::parse("UInt64(min_value=1)").unwrap()), "u64");
+        assert_eq!(rust_type(&TypeRef::parse("List(String)").unwrap()), "Vec<String>");
+        assert_eq!(rust_type(&TypeRef::parse("Timestamp?").unwrap()), "Option<String>");
+        assert_eq!(rust_type(&TypeRef::parse("List(common.Photo?)?").unwrap()), "Option<Vec<Option<common::Photo>>>");
+    }
+
+    #[test]
+    fn test_to_rust() {
+        let src = "namespace account\n\nstruct SetProfilePhotoArg\n    photo PhotoSourceArg\n        \"Image to set as the user's new profile photo.\"\n\n    example default\n        photo = default\n        ";
+        let ns = Namespace::parse(src).unwrap();
+        let code = to_rust(&ns);
+
+        assert!(code.contains("#[derive(Debug, Clone)]\npub struct SetProfilePhotoArg {"));
+        assert!(code.contains("    /// Image to set as the user's new profile photo.\n"));
+        assert!(code.contains("pub photo: PhotoSourceArg"));
+    }
+
+    #[test]
+    fn test_to_rust_field_names() {
+        let src = r#"namespace files
+
+struct ListFolderArg
+    path String
+    recursive Boolean = false
+    includeMediaInfo Boolean
+    limit UInt32(min_value=1, max_value=2000)?
+"#;
+        let ns = Namespace::parse(src).unwrap();
+        let code = to_rust(&ns);
+
+        assert!(code.contains("pub path: String,"));
+        assert!(code.contains("pub recursive: bool,"));
+        assert!(code.contains("pub include_media_info: bool,"));
+        assert!(code.contains("pub limit: Option<u32>,"));
+    }
+
+    #[test]
+    fn test_to_rust_keyword_field_names() {
+        let src = r#"namespace files
+
+struct SearchMatch
+    type String
+    match Boolean
+    ref UInt32?
+    self String
+    matchType String
+"#;
+        let ns = Namespace::parse(src).unwrap();
+        let code = to_rust(&ns);
+
+        assert!(code.contains("pub r#type: String,"));
+        assert!(code.contains("pub r#match: bool,"));
+        assert!(code.contains("pub r#ref: Option<u32>,"));
+        assert!(code.contains("pub self_: String,"));
+        assert!(code.contains("pub match_type: String,"));
+    }
+}
@@ -0,0 +1,93 @@
+use std::error::Error;
+use std::fmt;
+use std::fmt::Formatter;
+use pest::error::Error as PestError;
+use crate::dropbox::stone::Rule;
+
+/// Error while building AST from the Stone source.
+#[derive(Debug)]
+pub enum AstError {
+    /// The source does not match the Stone grammar.
+    Syntax(Box<PestError<Rule>>),
+
+    /// The parse tree does not contain the expected element.
+    MissingElement(Rule),
+
+    /// The string literal contains an unsupported or incomplete escape sequence like `\x`.
+    InvalidEscape(String),
+}
+
+impl From<PestError<Rule>> for AstError {
+    fn from(pe: PestError<Rule>) -> Self {
+        AstError::Syntax(Box::new(pe))
+    }
+}
+
+impl fmt::Display for AstError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AstError::Syntax(pe) => write!(f, "syntax error: {}", pe),
+            AstError::MissingElement(rule) => write!(f, "missing element {:?}", rule),
+            AstError::InvalidEscape(seq) => write!(f, "invalid escape sequence {}", seq),
+        }
+    }
+}
+
+impl Error for AstError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AstError::Syntax(pe) => Some(pe.as_ref()),
+            AstError::MissingElement(_) | AstError::InvalidEscape(_) => None,
+        }
+    }
+}
+
+/// Kind of the definition in the Stone namespace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DefinitionKind {
+    Alias,
+    Struct,
+    Union,
+    Route,
+}
+
+impl fmt::Display for DefinitionKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DefinitionKind::Alias => write!(f, "alias"),
+            DefinitionKind::Struct => write!(f, "struct"),
+            DefinitionKind::Union => write!(f, "union"),
+            DefinitionKind::Route => write!(f, "route"),
+        }
+    }
+}
+
+/// Semantic error found in the Stone namespace.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StoneError {
+    /// The route refers to the type that is not defined.
+    UnresolvedRouteType {
+        route: String,
+        type_name: String,
+    },
+
+    /// The name is declared twice in the namespace.
+    /// `kind` is the kind of the second declaration.
+    DuplicateDefinition {
+        name: String,
+        kind: DefinitionKind,
+    },
+}
+
+impl fmt::Display for StoneError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            StoneError::UnresolvedRouteType { route, type_name } =>
+                write!(f, "unresolved type {} in route {}", type_name, route),
+            StoneError::DuplicateDefinition { name, kind } =>
+                write!(f, "duplicate definition of {} {}", kind, name),
+        }
+    }
+}
+
+impl Error for StoneError {}
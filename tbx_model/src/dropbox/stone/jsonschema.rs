@@ -0,0 +1,119 @@
+use crate::dropbox::stone::ast::{escape_stone_string, unescape_stone_string, Namespace, StructDef, TypeRef, UnionDef};
+
+/// Returns JSON string literal. Escape sequences of the Stone string literal are compatible with JSON.
+fn json_string(s: &str) -> String {
+    escape_stone_string(s)
+}
+
+/// Returns JSON Schema of the type.
+/// Aliases in the namespace are resolved to the aliased type,
+/// and structs or unions are referred by `$ref` to the definitions.
+/// Namespace qualified reference like `common.Photo` refers to the definitions of `common.json`.
+fn type_schema(ns: &Namespace, t: &TypeRef, resolving: &[&str]) -> String {
+    match (&t.namespace, t.name.as_str(), &t.item) {
+        (None, "List", Some(item)) => format!("{{\"type\":\"array\",\"items\":{}}}", type_schema(ns, item, resolving)),
+        (None, "Boolean", _) => "{\"type\":\"boolean\"}".to_string(),
+        (None, "Float32" | "Float64", _) => "{\"type\":\"number\"}".to_string(),
+        (None, "Int32" | "Int64" | "UInt32" | "UInt64", _) => "{\"type\":\"integer\"}".to_string(),
+        (None, "Bytes" | "String" | "Timestamp", _) => "{\"type\":\"string\"}".to_string(),
+        (None, "Void", _) => "{\"type\":\"null\"}".to_string(),
+        (None, name, _) => match ns.aliases.iter().find(|a| a.name == name) {
+            // skip the alias under resolution to avoid infinite recursion on circular aliases
+            Some(a) if !resolving.contains(&name) => {
+                let mut r = resolving.to_vec();
+                r.push(name);
+                type_schema(ns, &a.type_ref, &r)
+            }
+            _ => format!("{{\"$ref\":{}}}", json_string(&format!("#/definitions/{}", name))),
+        },
+        (Some(other), name, _) => format!("{{\"$ref\":{}}}", json_string(&format!("{}.json#/definitions/{}", other, name))),
+    }
+}
+
+/// Docs keep escape sequences of the source, then unescape before escaping as JSON string.
+/// Malformed escape sequences are kept as is.
+fn description(doc: &Option<String>) -> String {
+    match doc {
+        Some(d) => format!("\"description\":{},", json_string(&unescape_stone_string(d).unwrap_or_else(|_| d.clone()))),
+        None => String::new(),
+    }
+}
+
+fn struct_schema(ns: &Namespace, s: &StructDef) -> String {
+    let properties: Vec<String> = s.fields.iter()
+        .map(|f| format!("{}:{}", json_string(&f.name), type_schema(ns, &f.type_ref, &[])))
+        .collect();
+    let required: Vec<String> = s.fields.iter()
+        .filter(|f| !f.type_ref.optional)
+        .map(|f| json_string(&f.name))
+        .collect();
+    format!("{}:{{{}\"type\":\"object\",\"properties\":{{{}}},\"required\":[{}]}}",
+            json_string(&s.name), description(&s.doc), properties.join(","), required.join(","))
+}
+
+/// Unions are serialized as an object with the `.tag` field.
+fn union_schema(u: &UnionDef) -> String {
+    format!("{}:{{{}\"type\":\"object\",\"properties\":{{\".tag\":{{\"type\":\"string\"}}}},\"required\":[\".tag\"]}}",
+            json_string(&u.name), description(&u.doc))
+}
+
+/// Generate JSON Schema (draft-07) of the structs and unions in the namespace.
+/// Each definition is placed under `definitions`. Fields inherited by `extends` are not included.
+pub fn to_json_schema(ns: &Namespace) -> String {
+    let definitions: Vec<String> = ns.structs.iter()
+        .map(|s| struct_schema(ns, s))
+        .chain(ns.unions.iter().map(union_schema))
+        .collect();
+    format!("{{\"$schema\":\"http://json-schema.org/draft-07/schema#\",\"title\":{},{}\"definitions\":{{{}}}}}",
+            json_string(&ns.name), description(&ns.doc), definitions.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dropbox::stone::ast::{Namespace, TypeRef};
+    use crate::dropbox::stone::jsonschema::{to_json_schema, type_schema};
+
+    #[test]
+    fn test_type_schema() {
+        let ns = Namespace::parse("namespace users\n\nalias AccountId = String(min_length=40)\n\nalias Ids = List(AccountId)\n").unwrap();
+        let schema = |t: &str| type_schema(&ns, &TypeRef::parse(t).unwrap(), &[]);
+
+        assert_eq!(schema("Int64"), r#"{"type":"integer"}"#);
+        assert_eq!(schema("String"), r#"{"type":"string"}"#);
+        assert_eq!(schema("Float64?"), r#"{"type":"number"}"#);
+        assert_eq!(schema("List(Boolean)"), r#"{"type":"array","items":{"type":"boolean"}}"#);
+        assert_eq!(schema("AccountId"), r#"{"type":"string"}"#);
+        assert_eq!(schema("Ids"), r#"{"type":"array","items":{"type":"string"}}"#);
+        assert_eq!(schema("BasicAccount"), r##"{"$ref":"#/definitions/BasicAccount"}"##);
+        assert_eq!(schema("common.Photo"), r##"{"$ref":"common.json#/definitions/Photo"}"##);
+    }
+
+    #[test]
+    fn test_to_json_schema() {
+        let src = r#"namespace users
+
+alias AccountId = String(min_length=40, max_length=40)
+
+struct GetAccountArg
+    "Say \"hi\" to the account."
+
+    account_id AccountId
+        "A user's account identifier."
+    include_photo Boolean?
+
+    example default
+        account_id = "dbid:AAH4f99T0taONIb-OurWxbNQ6ywGRopQngc"
+
+union GetAccountError
+    no_account
+        "The specified \"account_id\" does not exist."
+"#;
+        let ns = Namespace::parse(src).unwrap();
+        let schema = to_json_schema(&ns);
+
+        assert!(schema.starts_with(r#"{"$schema":"http://json-schema.org/draft-07/schema#","title":"users","definitions":{"#));
+        assert!(schema.contains(r#""GetAccountArg":{"description":"Say \"hi\" to the account.","type":"object","properties":{"account_id":{"type":"string"},"include_photo":{"type":"boolean"}},"required":["account_id"]}"#));
+        assert!(schema.contains(r#""GetAccountError":{"type":"object","properties":{".tag":{"type":"string"}},"required":[".tag"]}"#));
+        assert!(schema.ends_with("}}}"));
+    }
+}
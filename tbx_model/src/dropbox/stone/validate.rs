@@ -0,0 +1,147 @@
+use std::collections::HashSet;
+use crate::dropbox::stone::ast::{Namespace, TypeRef};
+use crate::dropbox::stone::error::{DefinitionKind, StoneError};
+
+const PRIMITIVE_TYPES: [&str; 11] = [
+    "Bytes", "Boolean", "Float32", "Float64", "Int32", "Int64", "UInt32", "UInt64", "String", "Timestamp", "Void",
+];
+
+/// Returns true if the type name resolves to the primitive type or the type defined in the namespace.
+/// Namespace qualified reference like `common.Photo` resolves if the namespace is imported,
+/// since definitions of other namespaces are not available here.
+fn is_resolvable(ns: &Namespace, t: &TypeRef) -> bool {
+    match (t.name.as_str(), &t.item, &t.namespace) {
+        ("List", Some(item), _) => is_resolvable(ns, item),
+        (_, _, Some(import)) => ns.imports.contains(import),
+        (name, _, None) => PRIMITIVE_TYPES.contains(&name)
+            || ns.aliases.iter().any(|a| a.name == name)
+            || ns.structs.iter().any(|s| s.name == name)
+            || ns.unions.iter().any(|u| u.name == name),
+    }
+}
+
+/// Validate that arg, result and error types of each route resolve to defined types.
+/// Returns an empty vector if all types are resolvable.
+pub fn validate_route_types(ns: &Namespace) -> Vec<StoneError> {
+    let mut errors: Vec<StoneError> = Vec::new();
+    for route in &ns.routes {
+        for type_ref in [&route.arg, &route.result, &route.error] {
+            if !is_resolvable(ns, type_ref) {
+                errors.push(StoneError::UnresolvedRouteType {
+                    route: route.name.clone(),
+                    type_name: type_ref.to_stone(),
+                });
+            }
+        }
+    }
+    errors
+}
+
+/// Validate that each name is declared once in the namespace.
+/// Aliases, structs and unions share the same name space as data types, and routes have their own.
+/// Returns the first duplicate found, in the order of aliases, structs, unions and routes.
+pub fn validate_unique_definitions(ns: &Namespace) -> Result<(), StoneError> {
+    let types = ns.aliases.iter().map(|a| (a.name.as_str(), DefinitionKind::Alias))
+        .chain(ns.structs.iter().map(|s| (s.name.as_str(), DefinitionKind::Struct)))
+        .chain(ns.unions.iter().map(|u| (u.name.as_str(), DefinitionKind::Union)));
+    let routes = ns.routes.iter().map(|r| (r.name.as_str(), DefinitionKind::Route));
+
+    for definitions in [types.collect::<Vec<(&str, DefinitionKind)>>(), routes.collect()] {
+        let mut seen: HashSet<&str> = HashSet::new();
+        for (name, kind) in definitions {
+            if !seen.insert(name) {
+                return Err(StoneError::DuplicateDefinition {
+                    name: name.to_string(),
+                    kind,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dropbox::stone::ast::Namespace;
+    use crate::dropbox::stone::error::{DefinitionKind, StoneError};
+    use crate::dropbox::stone::validate::{validate_route_types, validate_unique_definitions};
+
+    const SRC: &str = r#"namespace account
+
+import common
+
+alias PhotoId = String
+
+struct SetProfilePhotoArg
+    photo common.PhotoSourceArg
+
+struct SetProfilePhotoResult
+    profile_photo_url String
+
+union SetProfilePhotoError
+    file_type_error
+    other
+
+route set_profile_photo(SetProfilePhotoArg, SetProfilePhotoResult, SetProfilePhotoError)
+
+route get_profile_photo(PhotoId, List(common.Photo), Void)
+
+route delete_profile_photo(PhotoId, Void, DeleteProfilePhotoError)
+"#;
+
+    #[test]
+    fn test_validate_route_types() {
+        let ns = Namespace::parse(SRC).unwrap();
+        let errors = validate_route_types(&ns);
+
+        assert_eq!(errors, vec![StoneError::UnresolvedRouteType {
+            route: "delete_profile_photo".to_string(),
+            type_name: "DeleteProfilePhotoError".to_string(),
+        }]);
+        assert_eq!(errors[0].to_string(), "unresolved type DeleteProfilePhotoError in route delete_profile_photo");
+    }
+
+    #[test]
+    fn test_validate_route_types_resolvable() {
+        let src = SRC.replace("DeleteProfilePhotoError", "SetProfilePhotoError");
+        let ns = Namespace::parse(&src).unwrap();
+        assert!(validate_route_types(&ns).is_empty());
+
+        let src = SRC.replace("import common\n", "");
+        let ns = Namespace::parse(&src).unwrap();
+        assert_eq!(validate_route_types(&ns).len(), 2);
+        assert_eq!(validate_route_types(&ns)[0], StoneError::UnresolvedRouteType {
+            route: "get_profile_photo".to_string(),
+            type_name: "List(common.Photo)".to_string(),
+        });
+    }
+
+    #[test]
+    fn test_validate_unique_definitions() {
+        let ns = Namespace::parse(SRC).unwrap();
+        assert_eq!(validate_unique_definitions(&ns), Ok(()));
+
+        let src = SRC.replace("struct SetProfilePhotoArg", "struct Foo\n    name String\n\nstruct Foo\n    path String\n\nstruct SetProfilePhotoArg");
+        let ns = Namespace::parse(&src).unwrap();
+        let err = validate_unique_definitions(&ns).unwrap_err();
+        assert_eq!(err, StoneError::DuplicateDefinition {
+            name: "Foo".to_string(),
+            kind: DefinitionKind::Struct,
+        });
+        assert_eq!(err.to_string(), "duplicate definition of struct Foo");
+
+        let src = SRC.replace("struct SetProfilePhotoArg", "union PhotoId\n    other\n\nstruct SetProfilePhotoArg");
+        let ns = Namespace::parse(&src).unwrap();
+        assert_eq!(validate_unique_definitions(&ns).unwrap_err(), StoneError::DuplicateDefinition {
+            name: "PhotoId".to_string(),
+            kind: DefinitionKind::Union,
+        });
+
+        let src = format!("{}\nroute get_profile_photo(PhotoId, Void, Void)\n", SRC);
+        let ns = Namespace::parse(&src).unwrap();
+        assert_eq!(validate_unique_definitions(&ns).unwrap_err(), StoneError::DuplicateDefinition {
+            name: "get_profile_photo".to_string(),
+            kind: DefinitionKind::Route,
+        });
+    }
+}